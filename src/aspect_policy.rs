@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AspectPolicy {
+    Stretch,
+    Letterbox,
+    Pillarbox,
+    Crop,
+    Expand,
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AspectFit {
+    pub viewport: [usize; 4],
+    pub scale: [f32; 2],
+}
+
+impl AspectPolicy {
+    pub fn fit(&self, target_aspect: f32, width: usize, height: usize) -> AspectFit {
+        let fwidth = width as f32;
+        let fheight = height as f32;
+        let aspect = if height > 0usize {fwidth / fheight} else {target_aspect};
+        let full = [0usize, 0usize, width, height];
+
+        let (viewport, scale) = match *self {
+            AspectPolicy::Stretch => (full, [1f32, 1f32]),
+            AspectPolicy::Letterbox => if aspect < target_aspect {
+                let h = math::round(fwidth / target_aspect) as usize;
+                ([0usize, (height - h) / 2usize, width, h], [1f32, 1f32])
+            } else {
+                (full, [aspect / target_aspect, 1f32])
+            },
+            AspectPolicy::Pillarbox => if aspect > target_aspect {
+                let w = math::round(fheight * target_aspect) as usize;
+                ([(width - w) / 2usize, 0usize, w, height], [1f32, 1f32])
+            } else {
+                (full, [1f32, target_aspect / aspect])
+            },
+            AspectPolicy::Crop => if aspect > target_aspect {
+                (full, [1f32, target_aspect / aspect])
+            } else {
+                (full, [aspect / target_aspect, 1f32])
+            },
+            AspectPolicy::Expand => if aspect > target_aspect {
+                (full, [aspect / target_aspect, 1f32])
+            } else {
+                (full, [1f32, target_aspect / aspect])
+            },
+        };
+
+        AspectFit {
+            viewport: viewport,
+            scale: scale,
+        }
+    }
+}
+
+impl Default for AspectPolicy {
+    fn default() -> Self {
+        AspectPolicy::Stretch
+    }
+}
+
+pub fn get_letterbox_rects(viewport: &[usize; 4], width: usize, height: usize) -> Vec<[usize; 4]> {
+    let right = viewport[0] + viewport[2];
+    let top = viewport[1] + viewport[3];
+    let mut rects = Vec::new();
+
+    if viewport[0] > 0usize {
+        rects.push([0usize, 0usize, viewport[0], height]);
+    }
+    if right < width {
+        rects.push([right, 0usize, width - right, height]);
+    }
+    if viewport[1] > 0usize {
+        rects.push([viewport[0], 0usize, viewport[2], viewport[1]]);
+    }
+    if top < height {
+        rects.push([viewport[0], top, viewport[2], height - top]);
+    }
+
+    rects
+}
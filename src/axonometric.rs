@@ -0,0 +1,42 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use to_radians::ToRadians;
+
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Axonometric {
+    Isometric,
+    Dimetric,
+    Custom(f32, f32),
+}
+
+impl Axonometric {
+    pub fn get_pitch(&self) -> f32 {
+        match *self {
+            Axonometric::Isometric => 35.264389f32,
+            Axonometric::Dimetric => 30f32,
+            Axonometric::Custom(pitch, _) => pitch,
+        }
+    }
+    pub fn get_yaw(&self) -> f32 {
+        match *self {
+            Axonometric::Isometric | Axonometric::Dimetric => 45f32,
+            Axonometric::Custom(_, yaw) => yaw,
+        }
+    }
+    pub fn get_rotation(&self) -> [f32; 4] {
+        let half_yaw = self.get_yaw().to_radians() * 0.5f32;
+        let half_pitch = -self.get_pitch().to_radians() * 0.5f32;
+
+        let sy = math::sin(half_yaw);
+        let cy = math::cos(half_yaw);
+        let sp = math::sin(half_pitch);
+        let cp = math::cos(half_pitch);
+
+        [cy * sp, sy * cp, -sy * sp, cy * cp]
+    }
+}
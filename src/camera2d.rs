@@ -1,13 +1,37 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 use core::f32::EPSILON;
 
 use shared::Shared;
 use mat32;
+use mat4;
 use transform_components::{Transform2D, Transform3D};
 use scene_graph::{Entity, Component, ComponentManager, Id};
 
 use camera2d_manager::Camera2DManager;
+use camera2d_desc::Camera2DDesc;
+use presets::Camera2DPreset;
+use render_target::RenderTarget;
+use camera_error::CameraError;
+use clear_mode::ClearMode;
+use layer::{self, ALL_LAYERS};
+use pixel_perfect::PixelPerfect;
+use aspect_policy::{self, AspectPolicy};
+use parallax::ParallaxLayer;
+use easing::Easing;
+use math;
+
+
+#[derive(Clone, Copy)]
+struct Zoom {
+    from: f32,
+    to: f32,
+    elapsed: f32,
+    duration: f32,
+    easing: Easing,
+    anchor: Option<([f32; 2], [f32; 2], [f32; 2])>,
+}
 
 
 struct Camera2DData {
@@ -20,15 +44,38 @@ struct Camera2DData {
     inv_width: f32,
     inv_height: f32,
 
+    render_target: RenderTarget,
+
     aspect: f32,
 
     auto_resize: bool,
     background: [f32; 4],
+    clear_mode: ClearMode,
+    clear_depth: f32,
+    clear_stencil: u32,
+
+    layer_mask: u32,
 
     orthographic_size: f32,
 
+    offset: [f32; 2],
+    rotation: f32,
+    local_zoom: f32,
+    zoom_animation: Option<Zoom>,
+
+    near: f32,
+    far: f32,
+    pixel_perfect: Option<PixelPerfect>,
+    target_aspect: Option<f32>,
+    aspect_policy: AspectPolicy,
+
     projection: [f32; 6],
+    previous_view_projection: [f32; 6],
+    current_view_projection: [f32; 6],
+    has_view_projection: bool,
     view: [f32; 6],
+    projection3d: [f32; 16],
+    view3d: [f32; 16],
 
     needs_update: bool,
     active: bool,
@@ -53,15 +100,38 @@ impl Camera2D {
                 inv_width: 1f32 / 512f32,
                 inv_height: 1f32 / 512f32,
 
+                render_target: RenderTarget::screen(512usize, 512usize),
+
                 auto_resize: true,
                 background: [0f32, 0f32, 0f32, 1f32],
+                clear_mode: ClearMode::SolidColor,
+                clear_depth: 1f32,
+                clear_stencil: 0u32,
+
+                layer_mask: ALL_LAYERS,
 
                 aspect: 1f32,
 
                 orthographic_size: 2f32,
 
+                offset: [0f32, 0f32],
+                rotation: 0f32,
+                local_zoom: 1f32,
+                zoom_animation: None,
+
+                near: -1f32,
+                far: 1f32,
+                pixel_perfect: None,
+                target_aspect: None,
+                aspect_policy: AspectPolicy::Stretch,
+
                 projection: mat32::new_identity(),
+                previous_view_projection: mat32::new_identity(),
+                current_view_projection: mat32::new_identity(),
+                has_view_projection: false,
                 view: mat32::new_identity(),
+                projection3d: mat4::new_identity(),
+                view3d: mat4::new_identity(),
 
                 needs_update: true,
                 active: true,
@@ -69,6 +139,71 @@ impl Camera2D {
         }
     }
 
+    pub fn from_desc(desc: &Camera2DDesc) -> Self {
+        let mut camera = Camera2D::new();
+        camera.apply_desc(desc);
+        camera
+    }
+    pub fn describe(&self) -> Camera2DDesc {
+        let ref data = self.data;
+
+        Camera2DDesc {
+            width: data.width,
+            height: data.height,
+            render_target: data.render_target,
+            auto_resize: data.auto_resize,
+            background: data.background,
+            clear_mode: data.clear_mode,
+            clear_depth: data.clear_depth,
+            clear_stencil: data.clear_stencil,
+            layer_mask: data.layer_mask,
+            target_aspect: data.target_aspect,
+            aspect_policy: data.aspect_policy,
+            orthographic_size: data.orthographic_size,
+            near: data.near,
+            far: data.far,
+            pixel_perfect: data.pixel_perfect,
+            active: data.active,
+        }
+    }
+    pub fn apply_desc(&mut self, desc: &Camera2DDesc) -> &mut Self {
+        self.set_render_target(desc.render_target);
+
+        if desc.render_target.is_screen() {
+            self.set(desc.width, desc.height);
+        }
+
+        self
+            .set_clear_mode(desc.clear_mode)
+            .set_clear_depth(desc.clear_depth)
+            .set_clear_stencil(desc.clear_stencil)
+            .set_layer_mask(desc.layer_mask)
+            .set_target_aspect(desc.target_aspect)
+            .set_aspect_policy(desc.aspect_policy)
+            .set_pixel_perfect(desc.pixel_perfect);
+        {
+            let ref mut data = self.data;
+            data.orthographic_size = desc.orthographic_size;
+            data.near = desc.near;
+            data.far = desc.far;
+            data.needs_update = true;
+        }
+
+        self.set_auto_resize(desc.auto_resize);
+        self.set_background(&desc.background);
+
+        if desc.active {
+            self.set_active();
+        } else {
+            self.__set_active(false);
+        }
+        self
+    }
+    pub fn apply_preset(&mut self, preset: Camera2DPreset) -> &mut Self {
+        let desc = preset.apply(self.describe());
+        self.apply_desc(&desc)
+    }
+
     pub fn get_manager(&self) -> Option<Camera2DManager> {
         self.data.camera_manager.clone()
     }
@@ -77,21 +212,26 @@ impl Camera2D {
     }
 
     pub fn set(&mut self, width: usize, height: usize) -> &mut Self {
-        {
-            let ref mut data = self.data;
-            let fwidth = width as f32;
-            let fheight = height as f32;
+        if self.data.render_target.is_screen() {
+            self.data.render_target.width = width;
+            self.data.render_target.height = height;
+            self.set_size(width, height);
+        }
+        self
+    }
+    fn set_size(&mut self, width: usize, height: usize) {
+        let ref mut data = self.data;
+        let fwidth = width as f32;
+        let fheight = height as f32;
 
-            data.width = width;
-            data.height = height;
+        data.width = width;
+        data.height = height;
 
-            data.inv_width = 1f32 / fwidth;
-            data.inv_height = 1f32 / fheight;
+        data.inv_width = 1f32 / fwidth;
+        data.inv_height = 1f32 / fheight;
 
-            data.aspect = fwidth / fheight;
-            data.needs_update = true;
-        }
-        self
+        data.aspect = fwidth / fheight;
+        data.needs_update = true;
     }
 
     pub fn __set_active(&mut self, active: bool) {
@@ -110,6 +250,22 @@ impl Camera2D {
         self
     }
 
+    pub fn get_render_target(&self) -> &RenderTarget {
+        &self.data.render_target
+    }
+    pub fn set_render_target(&mut self, render_target: RenderTarget) -> &mut Self {
+        self.data.render_target = render_target;
+        self.set_size(render_target.width, render_target.height);
+        self
+    }
+
+    pub fn resize_screen(&mut self, width: usize, height: usize) -> &mut Self {
+        if self.data.auto_resize && self.data.render_target.is_screen() {
+            self.set(width, height);
+        }
+        self
+    }
+
     pub fn get_auto_resize(&self) -> bool {
         self.data.auto_resize
     }
@@ -124,48 +280,397 @@ impl Camera2D {
         self.data.background = *background;
     }
 
+    pub fn get_clear_mode(&self) -> ClearMode {
+        self.data.clear_mode
+    }
+    pub fn set_clear_mode(&mut self, clear_mode: ClearMode) -> &mut Self {
+        self.data.clear_mode = clear_mode;
+        self
+    }
+    pub fn get_clear_depth(&self) -> f32 {
+        self.data.clear_depth
+    }
+    pub fn set_clear_depth(&mut self, clear_depth: f32) -> &mut Self {
+        self.data.clear_depth = clear_depth;
+        self
+    }
+    pub fn get_clear_stencil(&self) -> u32 {
+        self.data.clear_stencil
+    }
+    pub fn set_clear_stencil(&mut self, clear_stencil: u32) -> &mut Self {
+        self.data.clear_stencil = clear_stencil;
+        self
+    }
+
     pub fn set_width(&mut self, width: usize) -> &mut Self {
+        let height = self.data.height;
+        self.set(width, height)
+    }
+    pub fn set_height(&mut self, height: usize) -> &mut Self {
+        let width = self.data.width;
+        self.set(width, height)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.data.width
+    }
+    pub fn get_height(&self) -> usize {
+        self.data.height
+    }
+    pub fn get_inv_width(&self) -> f32 {
+        self.data.inv_width
+    }
+    pub fn get_inv_height(&self) -> f32 {
+        self.data.inv_height
+    }
+    pub fn get_aspect(&self) -> f32 {
+        self.data.aspect
+    }
+
+    pub fn try_set(&mut self, width: usize, height: usize) -> Result<&mut Self, CameraError> {
+        if width == 0usize {
+            Err(CameraError::InvalidWidth(width))
+        } else if height == 0usize {
+            Err(CameraError::InvalidHeight(height))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(width, height))
+        } else {
+            Ok(self.set(width, height))
+        }
+    }
+    pub fn try_set_width(&mut self, width: usize) -> Result<&mut Self, CameraError> {
+        if width == 0usize {
+            Err(CameraError::InvalidWidth(width))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(width, self.data.height))
+        } else {
+            Ok(self.set_width(width))
+        }
+    }
+    pub fn try_set_height(&mut self, height: usize) -> Result<&mut Self, CameraError> {
+        if height == 0usize {
+            Err(CameraError::InvalidHeight(height))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(self.data.width, height))
+        } else {
+            Ok(self.set_height(height))
+        }
+    }
+
+    pub fn set_orthographic_size(&mut self, orthographic_size: f32) -> &mut Self {
         {
             let ref mut data = self.data;
-            let fwidth = width as f32;
-
-            data.width = width;
-            data.inv_width = 1f32 / fwidth;
-            data.aspect = fwidth / data.height as f32;
+            data.orthographic_size = if orthographic_size > 0f32 {orthographic_size} else {EPSILON};
             data.needs_update = true;
         }
         self
     }
-    pub fn set_height(&mut self, height: usize) -> &mut Self {
+    pub fn get_orthographic_size(&self) -> f32 {
+        self.data.orthographic_size
+    }
+    pub fn try_set_orthographic_size(&mut self, orthographic_size: f32) -> Result<&mut Self, CameraError> {
+        if orthographic_size.is_finite() && orthographic_size > 0f32 {
+            self.data.orthographic_size = orthographic_size;
+            self.data.needs_update = true;
+            Ok(self)
+        } else {
+            Err(CameraError::InvalidOrthographicSize(orthographic_size))
+        }
+    }
+
+    pub fn set_pixel_perfect(&mut self, pixel_perfect: Option<PixelPerfect>) -> &mut Self {
         {
             let ref mut data = self.data;
-            let fheight = height as f32;
+            data.pixel_perfect = pixel_perfect;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_pixel_perfect(&self) -> Option<PixelPerfect> {
+        self.data.pixel_perfect
+    }
+    pub fn get_pixel_scale(&self) -> usize {
+        match self.data.pixel_perfect {
+            Some(ref pixel_perfect) => pixel_perfect.get_scale(self.data.width, self.data.height),
+            None => 1usize,
+        }
+    }
 
-            data.height = height;
-            data.inv_height = 1f32 / fheight;
-            data.aspect = data.width as f32 / fheight;
+    pub fn set_target_aspect(&mut self, target_aspect: Option<f32>) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.target_aspect = match target_aspect {
+                Some(aspect) if aspect.is_finite() && aspect > 0f32 => Some(aspect),
+                _ => None,
+            };
             data.needs_update = true;
         }
         self
     }
+    pub fn get_target_aspect(&self) -> Option<f32> {
+        self.data.target_aspect
+    }
+    pub fn try_set_target_aspect(&mut self, target_aspect: Option<f32>) -> Result<&mut Self, CameraError> {
+        match target_aspect {
+            Some(aspect) if !(aspect.is_finite() && aspect > 0f32) => Err(CameraError::InvalidTargetAspect(aspect)),
+            _ => Ok(self.set_target_aspect(target_aspect)),
+        }
+    }
+    pub fn set_aspect_policy(&mut self, aspect_policy: AspectPolicy) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.aspect_policy = aspect_policy;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_aspect_policy(&self) -> AspectPolicy {
+        self.data.aspect_policy
+    }
 
-    pub fn get_width(&self) -> usize {
-        self.data.width
+    pub fn get_viewport(&self) -> [usize; 4] {
+        let ref data = self.data;
+
+        if let Some(ref pixel_perfect) = data.pixel_perfect {
+            pixel_perfect.get_viewport(data.width, data.height)
+        } else if let Some(target_aspect) = data.target_aspect {
+            data.aspect_policy.fit(target_aspect, data.width, data.height).viewport
+        } else {
+            [0usize, 0usize, data.width, data.height]
+        }
     }
-    pub fn get_height(&self) -> usize {
-        self.data.height
+    pub fn get_letterbox_rects(&self) -> Vec<[usize; 4]> {
+        aspect_policy::get_letterbox_rects(&self.get_viewport(), self.data.width, self.data.height)
     }
 
-    pub fn set_orthographic_size(&mut self, orthographic_size: f32) -> &mut Self {
+    pub fn set_near(&mut self, near: f32) -> &mut Self {
         {
             let ref mut data = self.data;
-            data.orthographic_size = if orthographic_size > 0f32 {orthographic_size} else {EPSILON};
+            data.near = near;
             data.needs_update = true;
         }
         self
     }
-    pub fn get_orthographic_size(&self) -> f32 {
-        self.data.orthographic_size
+    pub fn get_near(&self) -> f32 {
+        self.data.near
+    }
+    pub fn set_far(&mut self, far: f32) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.far = far;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_far(&self) -> f32 {
+        self.data.far
+    }
+    pub fn try_set_near(&mut self, near: f32) -> Result<&mut Self, CameraError> {
+        let far = self.data.far;
+
+        if near.is_finite() && near < far {
+            Ok(self.set_near(near))
+        } else {
+            Err(CameraError::InvalidNear(near, far))
+        }
+    }
+    pub fn try_set_far(&mut self, far: f32) -> Result<&mut Self, CameraError> {
+        let near = self.data.near;
+
+        if far.is_finite() && far > near {
+            Ok(self.set_far(far))
+        } else {
+            Err(CameraError::InvalidFar(near, far))
+        }
+    }
+
+    pub fn set_offset(&mut self, offset: &[f32; 2]) -> &mut Self {
+        self.data.offset = *offset;
+        self
+    }
+    pub fn get_offset(&self) -> &[f32; 2] {
+        &self.data.offset
+    }
+    pub fn set_rotation(&mut self, rotation: f32) -> &mut Self {
+        self.data.rotation = rotation;
+        self
+    }
+    pub fn get_rotation(&self) -> f32 {
+        self.data.rotation
+    }
+    pub fn set_local_zoom(&mut self, local_zoom: f32) -> &mut Self {
+        self.data.local_zoom = if local_zoom > 0f32 {local_zoom} else {EPSILON};
+        self
+    }
+    pub fn get_local_zoom(&self) -> f32 {
+        self.data.local_zoom
+    }
+    pub fn get_local_matrix(&self) -> Option<[f32; 6]> {
+        let ref data = self.data;
+
+        if data.offset == [0f32, 0f32] && data.rotation == 0f32 && data.local_zoom == 1f32 {
+            None
+        } else {
+            let mut m = mat32::new_identity();
+            let scale = 1f32 / data.local_zoom;
+            math::compose_mat32(
+                &mut m,
+                &data.offset,
+                &[math::cos(data.rotation), math::sin(data.rotation)],
+                &[scale, scale]
+            );
+            Some(m)
+        }
+    }
+
+    pub fn zoom_to(&mut self, orthographic_size: f32, duration: f32, easing: Easing) -> &mut Self {
+        self.data.zoom_animation = Some(Zoom {
+            from: self.data.orthographic_size,
+            to: orthographic_size,
+            elapsed: 0f32,
+            duration: duration,
+            easing: easing,
+            anchor: None,
+        });
+        self
+    }
+    pub fn zoom_to_point(&mut self, orthographic_size: f32, point: &[f32; 2], duration: f32, easing: Easing) -> &mut Self {
+        let anchor = match (self.get_position(), self.get_world_matrix()) {
+            (Some(position), Some(m)) => Some((
+                self.screen_to_world(point),
+                [m[4], m[5]],
+                [m[4] - position[0], m[5] - position[1]]
+            )),
+            _ => None,
+        };
+
+        self.data.zoom_animation = Some(Zoom {
+            from: self.data.orthographic_size,
+            to: orthographic_size,
+            elapsed: 0f32,
+            duration: duration,
+            easing: easing,
+            anchor: anchor,
+        });
+        self
+    }
+    pub fn is_zooming(&self) -> bool {
+        self.data.zoom_animation.is_some()
+    }
+    pub fn stop_zoom(&mut self) -> &mut Self {
+        self.data.zoom_animation = None;
+        self
+    }
+    pub fn update_zoom(&mut self, delta: f32) -> &mut Self {
+        let mut zoom = match self.data.zoom_animation.take() {
+            Some(zoom) => zoom,
+            None => return self,
+        };
+
+        zoom.elapsed += delta;
+
+        let t = if zoom.duration > 0f32 {zoom.elapsed / zoom.duration} else {1f32};
+        let orthographic_size = math::lerp(zoom.from, zoom.to, zoom.easing.ease(t));
+
+        self.set_orthographic_size(orthographic_size);
+
+        if let Some((anchor, center, offset)) = zoom.anchor {
+            let scale = self.data.orthographic_size / zoom.from;
+
+            self.set_position(&[
+                anchor[0] - (anchor[0] - center[0]) * scale - offset[0],
+                anchor[1] - (anchor[1] - center[1]) * scale - offset[1]
+            ]);
+        }
+
+        if t < 1f32 {
+            self.data.zoom_animation = Some(zoom);
+        }
+        self
+    }
+
+    pub fn screen_to_world(&self, point: &[f32; 2]) -> [f32; 2] {
+        let viewport = self.get_viewport();
+        let extents = self.get_extents();
+        let top = self.data.height as f32 - (viewport[1] + viewport[3]) as f32;
+
+        let x = ((point[0] - viewport[0] as f32) / viewport[2] as f32 * 2f32 - 1f32) * extents[0];
+        let y = (1f32 - (point[1] - top) / viewport[3] as f32 * 2f32) * extents[1];
+
+        if let Some(m) = self.get_world_matrix() {
+            [m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5]]
+        } else {
+            [x, y]
+        }
+    }
+
+    fn get_position(&self) -> Option<[f32; 2]> {
+        if let Some(entity) = self.get_entity() {
+            if let Some(ref transform2d) = entity.get_component::<Transform2D>() {
+                Some(*transform2d.get_position())
+            } else if let Some(ref transform3d) = entity.get_component::<Transform3D>() {
+                let position = transform3d.get_position();
+                Some([position[0], position[1]])
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+    fn set_position(&mut self, position: &[f32; 2]) {
+        if let Some(entity) = self.get_entity() {
+            if let Some(ref mut transform2d) = entity.get_component::<Transform2D>() {
+                transform2d.set_position(position);
+            } else if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+                let z = transform3d.get_position()[2];
+                transform3d.set_position(&[position[0], position[1], z]);
+            }
+        }
+    }
+
+    pub fn get_layer_mask(&self) -> u32 {
+        self.data.layer_mask
+    }
+    pub fn set_layer_mask(&mut self, layer_mask: u32) -> &mut Self {
+        self.data.layer_mask = layer_mask;
+        self
+    }
+    pub fn sees_layer(&self, layer_mask: u32) -> bool {
+        (self.data.layer_mask & layer_mask) != 0u32
+    }
+    pub fn sees_point(&mut self, point: &[f32; 2]) -> bool {
+        self.sees_circle(point, 0f32)
+    }
+    pub fn sees_circle(&mut self, center: &[f32; 2], radius: f32) -> bool {
+        let v = *self.get_view();
+        let p = *self.get_projection();
+
+        let x = v[0] * center[0] + v[2] * center[1] + v[4];
+        let y = v[1] * center[0] + v[3] * center[1] + v[5];
+        let r = radius * math::length2(v[0], v[1]);
+        let nx = p[0] * x + p[2] * y + p[4];
+        let ny = p[1] * x + p[3] * y + p[5];
+        let rx = r * math::length2(p[0], p[2]);
+        let ry = r * math::length2(p[1], p[3]);
+
+        nx >= -1f32 - rx && nx <= 1f32 + rx && ny >= -1f32 - ry && ny <= 1f32 + ry
+    }
+    pub fn sees_entity(&mut self, entity: &Entity) -> bool {
+        self.sees_entity_with_radius(entity, 0f32)
+    }
+    pub fn sees_entity_with_radius(&mut self, entity: &Entity, radius: f32) -> bool {
+        if !self.sees_layer(layer::get_entity_layer_mask(entity)) {
+            false
+        } else if let Some(ref mut transform2d) = entity.get_component::<Transform2D>() {
+            let m = transform2d.get_world_matrix();
+            self.sees_circle(&[m[12], m[13]], radius)
+        } else if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+            let m = *transform3d.get_world_matrix();
+            self.sees_circle(&[m[12], m[13]], radius)
+        } else {
+            true
+        }
     }
 
     pub fn get_view(&mut self) -> &[f32; 6] {
@@ -177,9 +682,27 @@ impl Camera2D {
             mat32::identity(&mut self.data.view);
         }
 
+        let pixel_perfect = self.data.pixel_perfect;
+        if let Some(pixel_perfect) = pixel_perfect {
+            let ref mut view = self.data.view;
+            view[4] = pixel_perfect.snap(view[4]);
+            view[5] = pixel_perfect.snap(view[5]);
+        }
+
         &self.data.view
     }
-    fn get_world_matrix(&self) -> Option<[f32; 6]> {
+    pub fn get_world_matrix(&self) -> Option<[f32; 6]> {
+        match (self.get_entity_matrix(), self.get_local_matrix()) {
+            (Some(entity_matrix), Some(local_matrix)) => {
+                let mut m = mat32::new_identity();
+                mat32::mul(&mut m, &entity_matrix, &local_matrix);
+                Some(m)
+            },
+            (Some(entity_matrix), None) => Some(entity_matrix),
+            (None, local_matrix) => local_matrix,
+        }
+    }
+    fn get_entity_matrix(&self) -> Option<[f32; 6]> {
         if let Some(entity) = self.get_entity() {
             if let Some(ref mut transform2d) = entity.get_component::<Transform2D>() {
                 Some(*transform2d.get_matrix())
@@ -195,22 +718,109 @@ impl Camera2D {
         }
     }
 
+    pub fn get_parallax_view(&self, layer: &ParallaxLayer) -> [f32; 6] {
+        let mut view = mat32::new_identity();
+
+        if let Some(m) = self.get_world_matrix() {
+            let (position, rotation, scale) = math::decompose_mat32(&m);
+            let mut world = mat32::new_identity();
+
+            math::compose_mat32(
+                &mut world,
+                &[position[0] * layer.factor[0], position[1] * layer.factor[1]],
+                &rotation,
+                &[
+                    math::lerp(1f32, scale[0], layer.zoom_factor),
+                    math::lerp(1f32, scale[1], layer.zoom_factor)
+                ]
+            );
+            mat32::inverse(&mut view, &world);
+        }
+
+        if let Some(ref pixel_perfect) = self.data.pixel_perfect {
+            view[4] = pixel_perfect.snap(view[4]);
+            view[5] = pixel_perfect.snap(view[5]);
+        }
+
+        view
+    }
+    pub fn get_parallax_projection(&self, layer: &ParallaxLayer, reference_orthographic_size: f32) -> [f32; 6] {
+        let mut extents = self.get_extents();
+
+        if self.data.pixel_perfect.is_none() {
+            let orthographic_size = self.data.orthographic_size;
+            let scale = math::lerp(reference_orthographic_size, orthographic_size, layer.zoom_factor) / orthographic_size;
+            extents[0] *= scale;
+            extents[1] *= scale;
+        }
+
+        let mut projection = mat32::new_identity();
+        mat32::orthographic(&mut projection, extents[1], extents[0], -extents[1], -extents[0]);
+        projection
+    }
+
+    pub fn get_view3d(&mut self) -> &[f32; 16] {
+        let view = *self.get_view();
+        math::mat4_from_mat32(&mut self.data.view3d, &view);
+        &self.data.view3d
+    }
+    pub fn get_projection3d(&mut self) -> &[f32; 16] {
+        if self.data.needs_update {
+            self.update_projection();
+        }
+        &self.data.projection3d
+    }
+
+    pub fn get_previous_view_projection(&self) -> &[f32; 6] {
+        &self.data.previous_view_projection
+    }
+    pub fn __end_frame(&mut self) {
+        let view = *self.get_view();
+        let projection = *self.get_projection();
+        let ref mut data = self.data;
+
+        data.previous_view_projection = data.current_view_projection;
+        mat32::mul(&mut data.current_view_projection, &projection, &view);
+
+        if !data.has_view_projection {
+            data.previous_view_projection = data.current_view_projection;
+            data.has_view_projection = true;
+        }
+    }
+
     pub fn get_projection(&mut self) -> &[f32; 6] {
         if self.data.needs_update {
             self.update_projection();
         }
         &self.data.projection
     }
+    pub fn get_extents(&self) -> [f32; 2] {
+        let ref data = self.data;
+
+        if let Some(ref pixel_perfect) = data.pixel_perfect {
+            let orthographic_size = pixel_perfect.get_orthographic_size();
+            [orthographic_size * pixel_perfect.get_aspect(), orthographic_size]
+        } else if let Some(target_aspect) = data.target_aspect {
+            let scale = data.aspect_policy.fit(target_aspect, data.width, data.height).scale;
+            [data.orthographic_size * target_aspect * scale[0], data.orthographic_size * scale[1]]
+        } else {
+            [data.orthographic_size * data.aspect, data.orthographic_size]
+        }
+    }
     fn update_projection(&mut self) {
+        let extents = self.get_extents();
         let ref mut data = self.data;
 
-        let orthographic_size = data.orthographic_size;
-        let right = orthographic_size * data.aspect;
+        let right = extents[0];
         let left = -right;
-        let top = orthographic_size;
+        let top = extents[1];
         let bottom = -top;
 
         mat32::orthographic(&mut data.projection, top, right, bottom, left);
+
+        let near = data.near;
+        let far = data.far;
+        mat4::orthographic(&mut data.projection3d, left, right, top, bottom, near, far);
     }
 }
 
@@ -1,15 +1,28 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 use shared::Shared;
+use mat32;
 use scene_graph::{Scene, Component, ComponentManager, Id};
 
 use camera2d::Camera2D;
+use easing::Easing;
+use math;
+
+
+struct Transition {
+    from: Camera2D,
+    elapsed: f32,
+    duration: f32,
+    easing: Easing,
+}
 
 
 struct Camera2DManagerData {
     scene: Option<Scene>,
     active_camera: Option<Camera2D>,
-    components: usize,
+    transition: Option<Transition>,
+    cameras: Vec<Camera2D>,
 }
 
 
@@ -25,7 +38,8 @@ impl Camera2DManager {
             data: Shared::new(Camera2DManagerData {
                 scene: None,
                 active_camera: None,
-                components: 0usize,
+                transition: None,
+                cameras: Vec::new(),
             })
         }
     }
@@ -37,6 +51,7 @@ impl Camera2DManager {
 
         camera.__set_active(true);
         self.data.active_camera = Some(camera.clone());
+        self.data.transition = None;
 
         self
     }
@@ -49,6 +64,110 @@ impl Camera2DManager {
     pub fn has_active_camera(&self) -> bool {
         self.data.active_camera.is_some()
     }
+    pub fn get_cameras(&self) -> &[Camera2D] {
+        &self.data.cameras
+    }
+
+    pub fn set_active_camera_blended(&mut self, camera: &mut Camera2D, duration: f32, easing: Easing) -> &Self {
+        let from = match self.data.active_camera {
+            Some(ref active_camera) if *active_camera != *camera => Some(active_camera.clone()),
+            _ => None,
+        };
+
+        self.set_active_camera(camera);
+
+        self.data.transition = match from {
+            Some(from) => if duration > 0f32 {
+                Some(Transition {
+                    from: from,
+                    elapsed: 0f32,
+                    duration: duration,
+                    easing: easing,
+                })
+            } else {
+                None
+            },
+            None => None,
+        };
+
+        self
+    }
+    pub fn is_transitioning(&self) -> bool {
+        self.data.transition.is_some()
+    }
+    pub fn update_transition(&mut self, delta: f32) -> &Self {
+        let done = if let Some(ref mut transition) = self.data.transition {
+            transition.elapsed += delta;
+            transition.elapsed >= transition.duration
+        } else {
+            false
+        };
+
+        if done {
+            self.data.transition = None;
+        }
+        self
+    }
+
+    pub fn get_active_view(&mut self) -> Option<[f32; 6]> {
+        let mut active_camera = match self.get_active_camera() {
+            Some(active_camera) => active_camera,
+            None => return None,
+        };
+
+        if let Some(ref transition) = self.data.transition {
+            let t = transition.easing.ease(transition.elapsed / transition.duration);
+            let identity = mat32::new_identity();
+            let from_world = transition.from.get_world_matrix().unwrap_or(identity);
+            let to_world = active_camera.get_world_matrix().unwrap_or(identity);
+
+            let (from_position, from_rotation, from_scale) = math::decompose_mat32(&from_world);
+            let (to_position, to_rotation, to_scale) = math::decompose_mat32(&to_world);
+
+            let mut world = mat32::new_identity();
+            math::compose_mat32(
+                &mut world,
+                &[
+                    math::lerp(from_position[0], to_position[0], t),
+                    math::lerp(from_position[1], to_position[1], t)
+                ],
+                &math::nlerp_rotation2(&from_rotation, &to_rotation, t),
+                &[
+                    math::lerp(from_scale[0], to_scale[0], t),
+                    math::lerp(from_scale[1], to_scale[1], t)
+                ]
+            );
+
+            let mut view = mat32::new_identity();
+            mat32::inverse(&mut view, &world);
+            Some(view)
+        } else {
+            Some(*active_camera.get_view())
+        }
+    }
+    pub fn get_active_projection(&mut self) -> Option<[f32; 6]> {
+        let mut active_camera = match self.get_active_camera() {
+            Some(active_camera) => active_camera,
+            None => return None,
+        };
+
+        if let Some(ref transition) = self.data.transition {
+            let t = transition.easing.ease(transition.elapsed / transition.duration);
+            let ref from = transition.from;
+            let mut projection = mat32::new_identity();
+
+            let from_extents = from.get_extents();
+            let to_extents = active_camera.get_extents();
+            let right = math::lerp(from_extents[0], to_extents[0], t);
+            let top = math::lerp(from_extents[1], to_extents[1], t);
+
+            mat32::orthographic(&mut projection, top, right, -top, -right);
+
+            Some(projection)
+        } else {
+            Some(*active_camera.get_projection())
+        }
+    }
 }
 
 impl ComponentManager for Camera2DManager {
@@ -67,12 +186,16 @@ impl ComponentManager for Camera2DManager {
 
     fn get_order(&self) -> usize { 0 }
     fn is_empty(&self) -> bool {
-        self.data.components == 0usize
+        self.data.cameras.is_empty()
     }
 
     fn clear(&mut self) {}
     fn init(&mut self) {}
-    fn update(&mut self) {}
+    fn update(&mut self) {
+        for camera in self.data.cameras.iter_mut() {
+            camera.__end_frame();
+        }
+    }
 
     fn add_component(&mut self, component: &mut Box<Component>) {
         let ref mut component = component.downcast_mut::<Camera2D>().unwrap();
@@ -83,12 +206,15 @@ impl ComponentManager for Camera2DManager {
             self.set_active_camera(component);
         }
 
-        self.data.components += 1;
+        self.data.cameras.push(component.clone());
     }
     fn remove_component(&mut self, component: &mut Box<Component>) {
         let mut component = component.downcast_mut::<Camera2D>().unwrap();
 
-        self.data.components -= 1;
+        let index = self.data.cameras.iter().position(|camera| camera == &*component);
+        if let Some(index) = index {
+            self.data.cameras.remove(index);
+        }
 
         if component.active() {
             component.__set_manager(None);
@@ -1,4 +1,5 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 use shared::Shared;
 use mat4;
@@ -7,6 +8,36 @@ use transform_components::{Transform2D, Transform3D};
 use scene_graph::{Entity, Component, ComponentManager, Id};
 
 use camera3d_manager::Camera3DManager;
+use camera3d_desc::Camera3DDesc;
+use presets::Camera3DPreset;
+use render_target::RenderTarget;
+use camera_error::CameraError;
+use clear_mode::ClearMode;
+use layer::{self, ALL_LAYERS};
+use shadow_cascades::{self, ShadowCascade};
+use frustum::FrustumSlice;
+use aspect_policy::{self, AspectPolicy};
+use axonometric::Axonometric;
+use easing::Easing;
+use math;
+
+
+const MORPH_MIN_FOV: f32 = 1f32;
+
+
+#[derive(Clone, Copy)]
+struct Morph {
+    to_orthographic: bool,
+    from_fov: f32,
+    to_fov: f32,
+    height: f32,
+    distance: f32,
+    elapsed: f32,
+    duration: f32,
+    easing: Easing,
+    fov: f32,
+    offset: f32,
+}
 
 
 struct Camera3DData {
@@ -19,19 +50,39 @@ struct Camera3DData {
     inv_width: f32,
     inv_height: f32,
 
+    render_target: RenderTarget,
+
     auto_resize: bool,
     background: [f32; 4],
+    clear_mode: ClearMode,
+    clear_depth: f32,
+    clear_stencil: u32,
+
+    layer_mask: u32,
 
     aspect: f32,
+    target_aspect: Option<f32>,
+    aspect_policy: AspectPolicy,
     fov: f32,
 
     orthographic_mode: bool,
     orthographic_size: f32,
+    morph: Option<Morph>,
 
     near: f32,
     far: f32,
+    reversed_z: bool,
+
+    jitter_enabled: bool,
+    jitter_length: usize,
+    jitter_index: usize,
+    jitter: [f32; 2],
 
     projection: [f32; 16],
+    unjittered_projection: [f32; 16],
+    previous_view_projection: [f32; 16],
+    current_view_projection: [f32; 16],
+    has_view_projection: bool,
     view: [f32; 16],
 
     needs_update: bool,
@@ -57,19 +108,39 @@ impl Camera3D {
                 inv_width: 1f32 / 512f32,
                 inv_height: 1f32 / 512f32,
 
+                render_target: RenderTarget::screen(512usize, 512usize),
+
                 auto_resize: true,
                 background: [0f32, 0f32, 0f32, 1f32],
+                clear_mode: ClearMode::SolidColor,
+                clear_depth: 1f32,
+                clear_stencil: 0u32,
+
+                layer_mask: ALL_LAYERS,
 
                 aspect: 1f32,
+                target_aspect: None,
+                aspect_policy: AspectPolicy::Stretch,
                 fov: 35f32,
 
                 orthographic_mode: false,
                 orthographic_size: 2f32,
+                morph: None,
 
                 near: 0.001f32,
                 far: 1000f32,
+                reversed_z: false,
+
+                jitter_enabled: false,
+                jitter_length: 8usize,
+                jitter_index: 0usize,
+                jitter: [0f32, 0f32],
 
                 projection: mat4::new_identity(),
+                unjittered_projection: mat4::new_identity(),
+                previous_view_projection: mat4::new_identity(),
+                current_view_projection: mat4::new_identity(),
+                has_view_projection: false,
                 view: mat4::new_identity(),
 
                 needs_update: true,
@@ -78,6 +149,80 @@ impl Camera3D {
         }
     }
 
+    pub fn from_desc(desc: &Camera3DDesc) -> Self {
+        let mut camera = Camera3D::new();
+        camera.apply_desc(desc);
+        camera
+    }
+    pub fn describe(&self) -> Camera3DDesc {
+        let ref data = self.data;
+
+        Camera3DDesc {
+            width: data.width,
+            height: data.height,
+            render_target: data.render_target,
+            auto_resize: data.auto_resize,
+            background: data.background,
+            clear_mode: data.clear_mode,
+            clear_depth: data.clear_depth,
+            clear_stencil: data.clear_stencil,
+            layer_mask: data.layer_mask,
+            target_aspect: data.target_aspect,
+            aspect_policy: data.aspect_policy,
+            fov: data.fov,
+            orthographic_mode: data.orthographic_mode,
+            orthographic_size: data.orthographic_size,
+            near: data.near,
+            far: data.far,
+            reversed_z: data.reversed_z,
+            active: data.active,
+        }
+    }
+    pub fn apply_desc(&mut self, desc: &Camera3DDesc) -> &mut Self {
+        self.set_render_target(desc.render_target);
+
+        if desc.render_target.is_screen() {
+            self.set(desc.width, desc.height);
+        }
+
+        self
+            .set_reversed_z(desc.reversed_z)
+            .set_clear_mode(desc.clear_mode)
+            .set_clear_depth(desc.clear_depth)
+            .set_clear_stencil(desc.clear_stencil)
+            .set_layer_mask(desc.layer_mask)
+            .set_target_aspect(desc.target_aspect)
+            .set_aspect_policy(desc.aspect_policy)
+            .set_fov(desc.fov)
+            .set_orthographic_mode(desc.orthographic_mode);
+        {
+            let ref mut data = self.data;
+            data.orthographic_size = desc.orthographic_size;
+            data.near = desc.near;
+            data.far = desc.far;
+            data.needs_update = true;
+        }
+
+        self.set_auto_resize(desc.auto_resize);
+        self.set_background(&desc.background);
+
+        if desc.active {
+            self.set_active();
+        } else {
+            self.__set_active(false);
+        }
+        self
+    }
+    pub fn apply_preset(&mut self, preset: Camera3DPreset) -> &mut Self {
+        let desc = preset.apply(self.describe());
+        self.apply_desc(&desc);
+
+        if preset == Camera3DPreset::Isometric {
+            self.set_axonometric(Axonometric::Isometric);
+        }
+        self
+    }
+
     pub fn get_manager(&self) -> Option<Camera3DManager> {
         self.data.camera_manager.clone()
     }
@@ -86,21 +231,26 @@ impl Camera3D {
     }
 
     pub fn set(&mut self, width: usize, height: usize) -> &mut Self {
-        {
-            let ref mut data = self.data;
-            let fwidth = width as f32;
-            let fheight = height as f32;
+        if self.data.render_target.is_screen() {
+            self.data.render_target.width = width;
+            self.data.render_target.height = height;
+            self.set_size(width, height);
+        }
+        self
+    }
+    fn set_size(&mut self, width: usize, height: usize) {
+        let ref mut data = self.data;
+        let fwidth = width as f32;
+        let fheight = height as f32;
 
-            data.width = width;
-            data.height = height;
+        data.width = width;
+        data.height = height;
 
-            data.inv_width = 1f32 / fwidth;
-            data.inv_height = 1f32 / fheight;
+        data.inv_width = 1f32 / fwidth;
+        data.inv_height = 1f32 / fheight;
 
-            data.aspect = fwidth / fheight;
-            data.needs_update = true;
-        }
-        self
+        data.aspect = fwidth / fheight;
+        data.needs_update = true;
     }
 
     pub fn __set_active(&mut self, active: bool) {
@@ -119,6 +269,22 @@ impl Camera3D {
         self
     }
 
+    pub fn get_render_target(&self) -> &RenderTarget {
+        &self.data.render_target
+    }
+    pub fn set_render_target(&mut self, render_target: RenderTarget) -> &mut Self {
+        self.data.render_target = render_target;
+        self.set_size(render_target.width, render_target.height);
+        self
+    }
+
+    pub fn resize_screen(&mut self, width: usize, height: usize) -> &mut Self {
+        if self.data.auto_resize && self.data.render_target.is_screen() {
+            self.set(width, height);
+        }
+        self
+    }
+
     pub fn get_auto_resize(&self) -> bool {
         self.data.auto_resize
     }
@@ -133,36 +299,136 @@ impl Camera3D {
         self.data.background = *background;
     }
 
+    pub fn get_clear_mode(&self) -> ClearMode {
+        self.data.clear_mode
+    }
+    pub fn set_clear_mode(&mut self, clear_mode: ClearMode) -> &mut Self {
+        self.data.clear_mode = clear_mode;
+        self
+    }
+    pub fn get_clear_depth(&self) -> f32 {
+        self.data.clear_depth
+    }
+    pub fn set_clear_depth(&mut self, clear_depth: f32) -> &mut Self {
+        self.data.clear_depth = clear_depth;
+        self
+    }
+    pub fn get_clear_stencil(&self) -> u32 {
+        self.data.clear_stencil
+    }
+    pub fn set_clear_stencil(&mut self, clear_stencil: u32) -> &mut Self {
+        self.data.clear_stencil = clear_stencil;
+        self
+    }
+
     pub fn set_width(&mut self, width: usize) -> &mut Self {
+        let height = self.data.height;
+        self.set(width, height)
+    }
+    pub fn set_height(&mut self, height: usize) -> &mut Self {
+        let width = self.data.width;
+        self.set(width, height)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.data.width
+    }
+    pub fn get_height(&self) -> usize {
+        self.data.height
+    }
+    pub fn get_inv_width(&self) -> f32 {
+        self.data.inv_width
+    }
+    pub fn get_inv_height(&self) -> f32 {
+        self.data.inv_height
+    }
+    pub fn get_aspect(&self) -> f32 {
+        self.data.aspect
+    }
+
+    pub fn try_set(&mut self, width: usize, height: usize) -> Result<&mut Self, CameraError> {
+        if width == 0usize {
+            Err(CameraError::InvalidWidth(width))
+        } else if height == 0usize {
+            Err(CameraError::InvalidHeight(height))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(width, height))
+        } else {
+            Ok(self.set(width, height))
+        }
+    }
+    pub fn try_set_width(&mut self, width: usize) -> Result<&mut Self, CameraError> {
+        if width == 0usize {
+            Err(CameraError::InvalidWidth(width))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(width, self.data.height))
+        } else {
+            Ok(self.set_width(width))
+        }
+    }
+    pub fn try_set_height(&mut self, height: usize) -> Result<&mut Self, CameraError> {
+        if height == 0usize {
+            Err(CameraError::InvalidHeight(height))
+        } else if !self.data.render_target.is_screen() {
+            Err(CameraError::InvalidRenderTargetSize(self.data.width, height))
+        } else {
+            Ok(self.set_height(height))
+        }
+    }
+
+    pub fn set_target_aspect(&mut self, target_aspect: Option<f32>) -> &mut Self {
         {
             let ref mut data = self.data;
-            let fwidth = width as f32;
-
-            data.width = width;
-            data.inv_width = 1f32 / fwidth;
-            data.aspect = fwidth / data.height as f32;
+            data.target_aspect = match target_aspect {
+                Some(aspect) if aspect.is_finite() && aspect > 0f32 => Some(aspect),
+                _ => None,
+            };
             data.needs_update = true;
         }
         self
     }
-    pub fn set_height(&mut self, height: usize) -> &mut Self {
+    pub fn get_target_aspect(&self) -> Option<f32> {
+        self.data.target_aspect
+    }
+    pub fn try_set_target_aspect(&mut self, target_aspect: Option<f32>) -> Result<&mut Self, CameraError> {
+        match target_aspect {
+            Some(aspect) if !(aspect.is_finite() && aspect > 0f32) => Err(CameraError::InvalidTargetAspect(aspect)),
+            _ => Ok(self.set_target_aspect(target_aspect)),
+        }
+    }
+    pub fn set_aspect_policy(&mut self, aspect_policy: AspectPolicy) -> &mut Self {
         {
             let ref mut data = self.data;
-            let fheight = height as f32;
-
-            data.height = height;
-            data.inv_height = 1f32 / fheight;
-            data.aspect = data.width as f32 / fheight;
+            data.aspect_policy = aspect_policy;
             data.needs_update = true;
         }
         self
     }
+    pub fn get_aspect_policy(&self) -> AspectPolicy {
+        self.data.aspect_policy
+    }
 
-    pub fn get_width(&self) -> usize {
-        self.data.width
+    pub fn get_viewport(&self) -> [usize; 4] {
+        let ref data = self.data;
+
+        if let Some(target_aspect) = data.target_aspect {
+            data.aspect_policy.fit(target_aspect, data.width, data.height).viewport
+        } else {
+            [0usize, 0usize, data.width, data.height]
+        }
     }
-    pub fn get_height(&self) -> usize {
-        self.data.height
+    pub fn get_letterbox_rects(&self) -> Vec<[usize; 4]> {
+        aspect_policy::get_letterbox_rects(&self.get_viewport(), self.data.width, self.data.height)
+    }
+    pub fn get_projection_aspect(&self) -> (f32, f32) {
+        let ref data = self.data;
+
+        if let Some(target_aspect) = data.target_aspect {
+            let scale = data.aspect_policy.fit(target_aspect, data.width, data.height).scale;
+            (target_aspect * scale[0] / scale[1], scale[1])
+        } else {
+            (data.aspect, 1f32)
+        }
     }
 
     pub fn set_fov(&mut self, fov: f32) -> &mut Self {
@@ -202,6 +468,33 @@ impl Camera3D {
     pub fn get_far(&self) -> f32 {
         self.data.far
     }
+    pub fn try_set_fov(&mut self, fov: f32) -> Result<&mut Self, CameraError> {
+        if fov > 0f32 && fov < 180f32 {
+            Ok(self.set_fov(fov))
+        } else {
+            Err(CameraError::InvalidFov(fov))
+        }
+    }
+    pub fn try_set_near(&mut self, near: f32) -> Result<&mut Self, CameraError> {
+        let far = self.data.far;
+
+        if near.is_finite() && near > 0f32 && near < far {
+            self.data.near = near;
+            self.data.needs_update = true;
+            Ok(self)
+        } else {
+            Err(CameraError::InvalidNear(near, far))
+        }
+    }
+    pub fn try_set_far(&mut self, far: f32) -> Result<&mut Self, CameraError> {
+        let near = self.data.near;
+
+        if far.is_finite() && far > near {
+            Ok(self.set_far(far))
+        } else {
+            Err(CameraError::InvalidFar(near, far))
+        }
+    }
 
     pub fn set_orthographic_mode(&mut self, orthographic_mode: bool) -> &mut Self {
         {
@@ -211,6 +504,21 @@ impl Camera3D {
         }
         self
     }
+    pub fn set_reversed_z(&mut self, reversed_z: bool) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            if data.reversed_z != reversed_z {
+                data.clear_depth = 1f32 - data.clear_depth;
+            }
+            data.reversed_z = reversed_z;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_reversed_z(&self) -> bool {
+        self.data.reversed_z
+    }
+
     pub fn get_orthographic_mode(&self) -> bool {
         self.data.orthographic_mode
     }
@@ -226,6 +534,264 @@ impl Camera3D {
     pub fn get_orthographic_size(&self) -> f32 {
         self.data.orthographic_size
     }
+    pub fn try_set_orthographic_size(&mut self, orthographic_size: f32) -> Result<&mut Self, CameraError> {
+        if orthographic_size.is_finite() && orthographic_size > 0f32 {
+            self.data.orthographic_size = orthographic_size;
+            self.data.needs_update = true;
+            Ok(self)
+        } else {
+            Err(CameraError::InvalidOrthographicSize(orthographic_size))
+        }
+    }
+
+    pub fn morph_to_orthographic(&mut self, focus_distance: f32, duration: f32, easing: Easing) -> &mut Self {
+        if !self.data.orthographic_mode && self.data.morph.is_none() {
+            let fov = self.data.fov;
+            let height = focus_distance * math::tan(fov.to_radians() * 0.5f32);
+
+            self.data.morph = Some(Morph {
+                to_orthographic: true,
+                from_fov: fov,
+                to_fov: MORPH_MIN_FOV,
+                height: height,
+                distance: focus_distance,
+                elapsed: 0f32,
+                duration: duration,
+                easing: easing,
+                fov: fov,
+                offset: 0f32,
+            });
+            self.update_morph(0f32);
+        }
+        self
+    }
+    pub fn morph_to_perspective(&mut self, duration: f32, easing: Easing) -> &mut Self {
+        if self.data.orthographic_mode && self.data.morph.is_none() {
+            let fov = self.data.fov;
+            let height = self.data.orthographic_size;
+
+            self.data.orthographic_mode = false;
+            self.data.morph = Some(Morph {
+                to_orthographic: false,
+                from_fov: MORPH_MIN_FOV,
+                to_fov: fov,
+                height: height,
+                distance: height / math::tan(fov.to_radians() * 0.5f32),
+                elapsed: 0f32,
+                duration: duration,
+                easing: easing,
+                fov: MORPH_MIN_FOV,
+                offset: 0f32,
+            });
+            self.update_morph(0f32);
+        }
+        self
+    }
+    pub fn is_morphing(&self) -> bool {
+        self.data.morph.is_some()
+    }
+    pub fn update_morph(&mut self, delta: f32) -> &mut Self {
+        let mut morph = match self.data.morph.take() {
+            Some(morph) => morph,
+            None => return self,
+        };
+
+        morph.elapsed += delta;
+
+        let t = if morph.duration > 0f32 {morph.elapsed / morph.duration} else {1f32};
+
+        if t < 1f32 {
+            morph.fov = math::lerp(morph.from_fov, morph.to_fov, morph.easing.ease(t));
+            morph.offset = morph.height / math::tan(morph.fov.to_radians() * 0.5f32) - morph.distance;
+            self.data.morph = Some(morph);
+        } else if morph.to_orthographic {
+            self.data.orthographic_mode = true;
+            self.data.orthographic_size = morph.height;
+        }
+
+        self.data.needs_update = true;
+        self
+    }
+
+    pub fn look_at(&mut self, target: &[f32; 3], up: &[f32; 3]) -> &mut Self {
+        let eye = self.get_position();
+        let basis = match math::look_basis(&eye, target, up) {
+            Some(basis) => basis,
+            None => return self,
+        };
+
+        if let Some(entity) = self.get_entity() {
+            if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+                let world = *transform3d.get_world_matrix();
+                let local = *transform3d.get_matrix();
+                transform3d.set_rotation(&math::local_rotation(&world, &local, &basis));
+            }
+        }
+        self
+    }
+
+    pub fn set_axonometric(&mut self, axonometric: Axonometric) -> &mut Self {
+        self.set_orthographic_mode(true);
+
+        if let Some(entity) = self.get_entity() {
+            if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+                transform3d.set_rotation(&axonometric.get_rotation());
+            }
+        }
+        self
+    }
+
+    pub fn screen_to_grid(&mut self, pixel: &[f32; 2], cell_size: f32) -> Option<[f32; 2]> {
+        let projection = *self.get_unjittered_projection();
+        let mut inv_view = mat4::new_identity();
+        mat4::inverse(&mut inv_view, self.get_view());
+
+        let a = math::transform_mat4_point(&inv_view, &self.unproject(&projection, pixel, 0f32));
+        let b = math::transform_mat4_point(&inv_view, &self.unproject(&projection, pixel, 1f32));
+        let dy = b[1] - a[1];
+
+        if math::abs(dy) < 0.000001f32 {
+            None
+        } else {
+            let t = -a[1] / dy;
+            Some([
+                math::lerp(a[0], b[0], t) / cell_size,
+                math::lerp(a[2], b[2], t) / cell_size
+            ])
+        }
+    }
+    pub fn grid_to_screen(&mut self, grid: &[f32; 2], cell_size: f32) -> [f32; 2] {
+        let view = *self.get_view();
+        let projection = *self.get_unjittered_projection();
+        let mut view_projection = mat4::new_identity();
+        mat4::mul(&mut view_projection, &projection, &view);
+
+        let ndc = math::transform_mat4_point(&view_projection, &[grid[0] * cell_size, 0f32, grid[1] * cell_size]);
+        let viewport = self.get_viewport();
+        let top = self.data.height as f32 - (viewport[1] + viewport[3]) as f32;
+
+        [
+            viewport[0] as f32 + (ndc[0] + 1f32) * 0.5f32 * viewport[2] as f32,
+            top + (1f32 - ndc[1]) * 0.5f32 * viewport[3] as f32
+        ]
+    }
+
+    pub fn frame_distance_for_sphere(&self, radius: f32) -> f32 {
+        let (aspect, scale) = self.get_projection_aspect();
+        let tan_y = math::tan(self.data.fov.to_radians() * 0.5f32) * scale;
+        let tan_x = tan_y * aspect;
+        let t = if tan_x < tan_y {tan_x} else {tan_y};
+
+        radius * math::sqrt(1f32 + t * t) / t
+    }
+    pub fn frame_distance_for_aabb(&self, min: &[f32; 3], max: &[f32; 3]) -> f32 {
+        self.frame_distance_for_sphere(aabb_radius(min, max))
+    }
+    pub fn frame_orthographic_size_for_sphere(&self, radius: f32) -> f32 {
+        let (aspect, scale) = self.get_projection_aspect();
+
+        if aspect < 1f32 {
+            radius / (aspect * scale)
+        } else {
+            radius / scale
+        }
+    }
+    pub fn frame_orthographic_size_for_aabb(&self, min: &[f32; 3], max: &[f32; 3]) -> f32 {
+        self.frame_orthographic_size_for_sphere(aabb_radius(min, max))
+    }
+
+    pub fn frame_sphere(&mut self, center: &[f32; 3], radius: f32) -> &mut Self {
+        let distance = if self.data.orthographic_mode {
+            let orthographic_size = self.frame_orthographic_size_for_sphere(radius);
+            self.set_orthographic_size(orthographic_size);
+            radius + self.data.near
+        } else {
+            self.frame_distance_for_sphere(radius)
+        };
+
+        let back = match self.get_world_matrix() {
+            Some(m) => math::normalize3(&[m[8], m[9], m[10]]),
+            None => [0f32, 0f32, 1f32],
+        };
+
+        if let Some(entity) = self.get_entity() {
+            if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+                let world = *transform3d.get_world_matrix();
+                let local = *transform3d.get_matrix();
+
+                transform3d.set_position(&math::local_position(&world, &local, &[
+                    center[0] + back[0] * distance,
+                    center[1] + back[1] * distance,
+                    center[2] + back[2] * distance
+                ]));
+            }
+        }
+        self
+    }
+    pub fn frame_aabb(&mut self, min: &[f32; 3], max: &[f32; 3]) -> &mut Self {
+        let center = [
+            (min[0] + max[0]) * 0.5f32,
+            (min[1] + max[1]) * 0.5f32,
+            (min[2] + max[2]) * 0.5f32
+        ];
+        self.frame_sphere(&center, aabb_radius(min, max))
+    }
+
+    fn get_position(&self) -> [f32; 3] {
+        match self.get_world_matrix() {
+            Some(m) => [m[12], m[13], m[14]],
+            None => [0f32, 0f32, 0f32],
+        }
+    }
+
+    pub fn get_layer_mask(&self) -> u32 {
+        self.data.layer_mask
+    }
+    pub fn set_layer_mask(&mut self, layer_mask: u32) -> &mut Self {
+        self.data.layer_mask = layer_mask;
+        self
+    }
+    pub fn sees_layer(&self, layer_mask: u32) -> bool {
+        (self.data.layer_mask & layer_mask) != 0u32
+    }
+    pub fn sees_sphere(&mut self, center: &[f32; 3], radius: f32) -> bool {
+        let view = *self.get_view();
+        let projection = *self.get_projection();
+        let mut m = mat4::new_identity();
+        mat4::mul(&mut m, &projection, &view);
+
+        for i in 0..6 {
+            let axis = i / 2;
+            let sign = if i % 2 == 0 {1f32} else {-1f32};
+
+            let a = m[3] + sign * m[axis];
+            let b = m[7] + sign * m[4 + axis];
+            let c = m[11] + sign * m[8 + axis];
+            let d = m[15] + sign * m[12 + axis];
+            let l = math::length3(a, b, c);
+
+            if (a * center[0] + b * center[1] + c * center[2] + d) < -radius * l {
+                return false;
+            }
+        }
+        true
+    }
+    pub fn sees_entity(&mut self, entity: &Entity) -> bool {
+        self.sees_entity_with_radius(entity, 0f32)
+    }
+    pub fn sees_entity_with_radius(&mut self, entity: &Entity, radius: f32) -> bool {
+        if !self.sees_layer(layer::get_entity_layer_mask(entity)) {
+            false
+        } else if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+            let m = *transform3d.get_world_matrix();
+            self.sees_sphere(&[m[12], m[13], m[14]], radius)
+        } else if let Some(ref mut transform2d) = entity.get_component::<Transform2D>() {
+            let m = transform2d.get_world_matrix();
+            self.sees_sphere(&[m[12], m[13], m[14]], radius)
+        } else {
+            true
+        }
+    }
 
     pub fn get_view(&mut self) -> &[f32; 16] {
         let world_matrix = self.get_world_matrix();
@@ -235,9 +801,13 @@ impl Camera3D {
         } else {
             mat4::identity(&mut self.data.view);
         }
+        let morph = self.data.morph;
+        if let Some(morph) = morph {
+            self.data.view[14] -= morph.offset;
+        }
         &self.data.view
     }
-    fn get_world_matrix(&self) -> Option<[f32; 16]> {
+    pub fn get_world_matrix(&self) -> Option<[f32; 16]> {
         if let Some(entity) = self.get_entity() {
             if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
                 Some(*(transform3d.get_world_matrix()))
@@ -251,6 +821,153 @@ impl Camera3D {
         }
     }
 
+    pub fn set_jitter_enabled(&mut self, jitter_enabled: bool) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.jitter_enabled = jitter_enabled;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_jitter_enabled(&self) -> bool {
+        self.data.jitter_enabled
+    }
+    pub fn set_jitter_length(&mut self, jitter_length: usize) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.jitter_length = if jitter_length > 0usize {jitter_length} else {1usize};
+            data.jitter_index = data.jitter_index % data.jitter_length;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_jitter_length(&self) -> usize {
+        self.data.jitter_length
+    }
+    pub fn get_jitter(&self) -> [f32; 2] {
+        if self.data.jitter_enabled {
+            self.data.jitter
+        } else {
+            [0f32, 0f32]
+        }
+    }
+    pub fn advance_jitter(&mut self) -> &mut Self {
+        {
+            let ref mut data = self.data;
+            data.jitter_index = (data.jitter_index + 1usize) % data.jitter_length;
+            data.needs_update = true;
+        }
+        self
+    }
+    pub fn get_unjittered_projection(&mut self) -> &[f32; 16] {
+        if self.data.needs_update {
+            self.update_projection();
+        }
+        &self.data.unjittered_projection
+    }
+    pub fn get_previous_view_projection(&self) -> &[f32; 16] {
+        &self.data.previous_view_projection
+    }
+    pub fn __end_frame(&mut self) {
+        let view = *self.get_view();
+        let projection = *self.get_unjittered_projection();
+        {
+            let ref mut data = self.data;
+
+            data.previous_view_projection = data.current_view_projection;
+            mat4::mul(&mut data.current_view_projection, &projection, &view);
+
+            if !data.has_view_projection {
+                data.previous_view_projection = data.current_view_projection;
+                data.has_view_projection = true;
+            }
+        }
+
+        if self.data.jitter_enabled {
+            self.advance_jitter();
+        }
+    }
+
+    pub fn get_shadow_cascades(
+        &mut self, light_direction: &[f32; 3], count: usize, lambda: f32, shadow_map_size: usize,
+        z_extension: f32
+    ) -> Vec<ShadowCascade> {
+        shadow_cascades::compute_shadow_cascades(self, light_direction, count, lambda, shadow_map_size, z_extension)
+    }
+
+    pub fn get_frustum_corners(&mut self) -> [[f32; 3]; 8] {
+        let near = self.data.near;
+        let far = self.data.far;
+        self.get_frustum_slice_corners(near, far)
+    }
+    pub fn get_frustum_slice(&mut self, near: f32, far: f32) -> FrustumSlice {
+        FrustumSlice::new(near, far, self.get_frustum_slice_corners(near, far))
+    }
+    pub fn get_frustum_slice_corners(&mut self, near: f32, far: f32) -> [[f32; 3]; 8] {
+        let (aspect, scale) = self.get_projection_aspect();
+
+        let (near_width, near_height, far_width, far_height) = if self.data.orthographic_mode {
+            let height = self.data.orthographic_size * scale;
+            let width = height * aspect;
+            (width, height, width, height)
+        } else {
+            let t = math::tan(self.data.fov.to_radians() * 0.5f32) * scale;
+            (near * t * aspect, near * t, far * t * aspect, far * t)
+        };
+
+        let mut inv_view = mat4::new_identity();
+        mat4::inverse(&mut inv_view, self.get_view());
+
+        [
+            math::transform_mat4_point(&inv_view, &[-near_width, -near_height, -near]),
+            math::transform_mat4_point(&inv_view, &[near_width, -near_height, -near]),
+            math::transform_mat4_point(&inv_view, &[near_width, near_height, -near]),
+            math::transform_mat4_point(&inv_view, &[-near_width, near_height, -near]),
+            math::transform_mat4_point(&inv_view, &[-far_width, -far_height, -far]),
+            math::transform_mat4_point(&inv_view, &[far_width, -far_height, -far]),
+            math::transform_mat4_point(&inv_view, &[far_width, far_height, -far]),
+            math::transform_mat4_point(&inv_view, &[-far_width, far_height, -far])
+        ]
+    }
+
+    pub fn linearize_depth(&mut self, depth: f32) -> f32 {
+        let p = *self.get_projection();
+        let ndc = depth * 2f32 - 1f32;
+
+        (ndc * p[15] - p[14]) / (ndc * p[11] - p[10])
+    }
+    pub fn depth_from_linear(&mut self, linear_depth: f32) -> f32 {
+        let p = *self.get_projection();
+        let z = -linear_depth;
+        let ndc = (p[10] * z + p[14]) / (p[11] * z + p[15]);
+
+        ndc * 0.5f32 + 0.5f32
+    }
+    pub fn view_position_from_depth(&mut self, pixel: &[f32; 2], depth: f32) -> [f32; 3] {
+        let projection = *self.get_projection();
+        self.unproject(&projection, pixel, depth)
+    }
+    pub fn world_position_from_depth(&mut self, pixel: &[f32; 2], depth: f32) -> [f32; 3] {
+        let view_position = self.view_position_from_depth(pixel, depth);
+        let mut inv_view = mat4::new_identity();
+        mat4::inverse(&mut inv_view, self.get_view());
+
+        math::transform_mat4_point(&inv_view, &view_position)
+    }
+    fn unproject(&self, projection: &[f32; 16], pixel: &[f32; 2], depth: f32) -> [f32; 3] {
+        let mut inv_projection = mat4::new_identity();
+        mat4::inverse(&mut inv_projection, projection);
+
+        let viewport = self.get_viewport();
+        let top = self.data.height as f32 - (viewport[1] + viewport[3]) as f32;
+
+        math::transform_mat4_point(&inv_projection, &[
+            (pixel[0] - viewport[0] as f32) / viewport[2] as f32 * 2f32 - 1f32,
+            1f32 - (pixel[1] - top) / viewport[3] as f32 * 2f32,
+            depth * 2f32 - 1f32
+        ])
+    }
+
     pub fn get_projection(&mut self) -> &[f32; 16] {
         if self.data.needs_update {
             self.update_projection();
@@ -258,11 +975,23 @@ impl Camera3D {
         &self.data.projection
     }
     fn update_projection(&mut self) {
-        if self.get_orthographic_mode() {
+        let (aspect, scale) = self.get_projection_aspect();
+        let morph = self.data.morph;
+
+        if let Some(morph) = morph {
             let ref mut data = self.data;
 
-            let orthographic_size = data.orthographic_size;
-            let right = orthographic_size * data.aspect;
+            let near = data.near + morph.offset;
+            let far = data.far + morph.offset;
+
+            mat4::perspective(&mut data.projection, morph.fov.to_radians(), aspect, near, far);
+            data.projection[0] /= scale;
+            data.projection[5] /= scale;
+        } else if self.get_orthographic_mode() {
+            let ref mut data = self.data;
+
+            let orthographic_size = data.orthographic_size * scale;
+            let right = orthographic_size * aspect;
             let left = -right;
             let top = orthographic_size;
             let bottom = -top;
@@ -274,15 +1003,53 @@ impl Camera3D {
             let ref mut data = self.data;
 
             let fov = data.fov;
-            let aspect = data.aspect;
             let near = data.near;
             let far = data.far;
 
             mat4::perspective(&mut data.projection, fov.to_radians(), aspect, near, far);
+            data.projection[0] /= scale;
+            data.projection[5] /= scale;
+        }
+
+        if self.data.reversed_z {
+            let ref mut projection = self.data.projection;
+            projection[2] = -projection[2];
+            projection[6] = -projection[6];
+            projection[10] = -projection[10];
+            projection[14] = -projection[14];
+        }
+
+        let viewport = self.get_viewport();
+        let ref mut data = self.data;
+        data.unjittered_projection = data.projection;
+
+        if data.jitter_enabled {
+            let index = data.jitter_index + 1usize;
+            data.jitter = [
+                math::halton(index, 2usize) - 0.5f32,
+                math::halton(index, 3usize) - 0.5f32
+            ];
+
+            let jx = data.jitter[0] * 2f32 / viewport[2] as f32;
+            let jy = data.jitter[1] * 2f32 / viewport[3] as f32;
+            let ref mut p = data.projection;
+
+            p[0] += jx * p[3];
+            p[4] += jx * p[7];
+            p[8] += jx * p[11];
+            p[12] += jx * p[15];
+            p[1] += jy * p[3];
+            p[5] += jy * p[7];
+            p[9] += jy * p[11];
+            p[13] += jy * p[15];
         }
     }
 }
 
+fn aabb_radius(min: &[f32; 3], max: &[f32; 3]) -> f32 {
+    math::length3(max[0] - min[0], max[1] - min[1], max[2] - min[2]) * 0.5f32
+}
+
 impl Component for Camera3D {
     fn get_id(&self) -> Id {
         Id::of::<Camera3D>()
@@ -0,0 +1,171 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use camera3d::Camera3D;
+use render_target::RenderTarget;
+use clear_mode::ClearMode;
+use camera_error::CameraError;
+use aspect_policy::AspectPolicy;
+use layer::ALL_LAYERS;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Camera3DDesc {
+    pub width: usize,
+    pub height: usize,
+    pub render_target: RenderTarget,
+    pub auto_resize: bool,
+    pub background: [f32; 4],
+    pub clear_mode: ClearMode,
+    pub clear_depth: f32,
+    pub clear_stencil: u32,
+    pub layer_mask: u32,
+    pub target_aspect: Option<f32>,
+    pub aspect_policy: AspectPolicy,
+    pub fov: f32,
+    pub orthographic_mode: bool,
+    pub orthographic_size: f32,
+    pub near: f32,
+    pub far: f32,
+    pub reversed_z: bool,
+    pub active: bool,
+}
+
+impl Camera3DDesc {
+    pub fn new() -> Self {
+        Camera3DDesc {
+            width: 512usize,
+            height: 512usize,
+            render_target: RenderTarget::screen(512usize, 512usize),
+            auto_resize: true,
+            background: [0f32, 0f32, 0f32, 1f32],
+            clear_mode: ClearMode::SolidColor,
+            clear_depth: 1f32,
+            clear_stencil: 0u32,
+            layer_mask: ALL_LAYERS,
+            target_aspect: None,
+            aspect_policy: AspectPolicy::Stretch,
+            fov: 35f32,
+            orthographic_mode: false,
+            orthographic_size: 2f32,
+            near: 0.001f32,
+            far: 1000f32,
+            reversed_z: false,
+            active: true,
+        }
+    }
+
+    pub fn size(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        if self.render_target.is_screen() {
+            self.render_target.width = width;
+            self.render_target.height = height;
+        }
+        self
+    }
+    pub fn render_target(mut self, render_target: RenderTarget) -> Self {
+        self.render_target = render_target;
+        self.size(render_target.width, render_target.height)
+    }
+    pub fn auto_resize(mut self, auto_resize: bool) -> Self {
+        self.auto_resize = auto_resize;
+        self
+    }
+    pub fn background(mut self, background: &[f32; 4]) -> Self {
+        self.background = *background;
+        self
+    }
+    pub fn clear_mode(mut self, clear_mode: ClearMode) -> Self {
+        self.clear_mode = clear_mode;
+        self
+    }
+    pub fn clear_depth(mut self, clear_depth: f32) -> Self {
+        self.clear_depth = clear_depth;
+        self
+    }
+    pub fn clear_stencil(mut self, clear_stencil: u32) -> Self {
+        self.clear_stencil = clear_stencil;
+        self
+    }
+    pub fn layer_mask(mut self, layer_mask: u32) -> Self {
+        self.layer_mask = layer_mask;
+        self
+    }
+    pub fn target_aspect(mut self, target_aspect: Option<f32>) -> Self {
+        self.target_aspect = target_aspect;
+        self
+    }
+    pub fn aspect_policy(mut self, aspect_policy: AspectPolicy) -> Self {
+        self.aspect_policy = aspect_policy;
+        self
+    }
+    pub fn fov(mut self, fov: f32) -> Self {
+        self.fov = fov;
+        self
+    }
+    pub fn orthographic_mode(mut self, orthographic_mode: bool) -> Self {
+        self.orthographic_mode = orthographic_mode;
+        self
+    }
+    pub fn orthographic_size(mut self, orthographic_size: f32) -> Self {
+        self.orthographic_size = orthographic_size;
+        self
+    }
+    pub fn near(mut self, near: f32) -> Self {
+        self.near = near;
+        self
+    }
+    pub fn far(mut self, far: f32) -> Self {
+        self.far = far;
+        self
+    }
+    pub fn reversed_z(mut self, reversed_z: bool) -> Self {
+        if self.reversed_z != reversed_z {
+            self.clear_depth = 1f32 - self.clear_depth;
+        }
+        self.reversed_z = reversed_z;
+        self
+    }
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), CameraError> {
+        if self.width == 0usize {
+            Err(CameraError::InvalidWidth(self.width))
+        } else if self.height == 0usize {
+            Err(CameraError::InvalidHeight(self.height))
+        } else if !self.render_target.is_screen() && (self.render_target.width != self.width || self.render_target.height != self.height) {
+            Err(CameraError::InvalidRenderTargetSize(self.width, self.height))
+        } else if let Some(target_aspect) = self.target_aspect.filter(|aspect| !(aspect.is_finite() && *aspect > 0f32)) {
+            Err(CameraError::InvalidTargetAspect(target_aspect))
+        } else if !(self.fov > 0f32 && self.fov < 180f32) {
+            Err(CameraError::InvalidFov(self.fov))
+        } else if !(self.near.is_finite() && self.near > 0f32 && self.near < self.far) {
+            Err(CameraError::InvalidNear(self.near, self.far))
+        } else if !(self.far.is_finite() && self.far > self.near) {
+            Err(CameraError::InvalidFar(self.near, self.far))
+        } else if !(self.orthographic_size.is_finite() && self.orthographic_size > 0f32) {
+            Err(CameraError::InvalidOrthographicSize(self.orthographic_size))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn build(&self) -> Camera3D {
+        Camera3D::from_desc(self)
+    }
+    pub fn try_build(&self) -> Result<Camera3D, CameraError> {
+        self.validate().map(|_| self.build())
+    }
+}
+
+impl Default for Camera3DDesc {
+    fn default() -> Self {
+        Camera3DDesc::new()
+    }
+}
@@ -1,15 +1,29 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 use shared::Shared;
+use mat4;
+use to_radians::ToRadians;
 use scene_graph::{Scene, Component, ComponentManager, Id};
 
 use camera3d::Camera3D;
+use easing::Easing;
+use math;
+
+
+struct Transition {
+    from: Camera3D,
+    elapsed: f32,
+    duration: f32,
+    easing: Easing,
+}
 
 
 struct Camera3DManagerData {
     scene: Option<Scene>,
     active_camera: Option<Camera3D>,
-    components: usize,
+    transition: Option<Transition>,
+    cameras: Vec<Camera3D>,
 }
 
 
@@ -25,7 +39,8 @@ impl Camera3DManager {
             data: Shared::new(Camera3DManagerData {
                 scene: None,
                 active_camera: None,
-                components: 0usize,
+                transition: None,
+                cameras: Vec::new(),
             })
         }
     }
@@ -37,6 +52,7 @@ impl Camera3DManager {
 
         camera.__set_active(true);
         self.data.active_camera = Some(camera.clone());
+        self.data.transition = None;
 
         self
     }
@@ -49,6 +65,141 @@ impl Camera3DManager {
     pub fn has_active_camera(&self) -> bool {
         self.data.active_camera.is_some()
     }
+    pub fn get_cameras(&self) -> &[Camera3D] {
+        &self.data.cameras
+    }
+
+    pub fn set_active_camera_blended(&mut self, camera: &mut Camera3D, duration: f32, easing: Easing) -> &Self {
+        let from = match self.data.active_camera {
+            Some(ref active_camera) if *active_camera != *camera => Some(active_camera.clone()),
+            _ => None,
+        };
+
+        self.set_active_camera(camera);
+
+        self.data.transition = match from {
+            Some(from) => if duration > 0f32 {
+                Some(Transition {
+                    from: from,
+                    elapsed: 0f32,
+                    duration: duration,
+                    easing: easing,
+                })
+            } else {
+                None
+            },
+            None => None,
+        };
+
+        self
+    }
+    pub fn is_transitioning(&self) -> bool {
+        self.data.transition.is_some()
+    }
+    pub fn update_transition(&mut self, delta: f32) -> &Self {
+        let done = if let Some(ref mut transition) = self.data.transition {
+            transition.elapsed += delta;
+            transition.elapsed >= transition.duration
+        } else {
+            false
+        };
+
+        if done {
+            self.data.transition = None;
+        }
+        self
+    }
+
+    pub fn get_active_view(&mut self) -> Option<[f32; 16]> {
+        let mut active_camera = match self.get_active_camera() {
+            Some(active_camera) => active_camera,
+            None => return None,
+        };
+
+        if let Some(ref transition) = self.data.transition {
+            let t = transition.easing.ease(transition.elapsed / transition.duration);
+            let identity = mat4::new_identity();
+            let from_world = transition.from.get_world_matrix().unwrap_or(identity);
+            let to_world = active_camera.get_world_matrix().unwrap_or(identity);
+
+            let (from_position, from_rotation, from_scale) = math::decompose_mat4(&from_world);
+            let (to_position, to_rotation, to_scale) = math::decompose_mat4(&to_world);
+
+            let mut world = mat4::new_identity();
+            math::compose_mat4(
+                &mut world,
+                &[
+                    math::lerp(from_position[0], to_position[0], t),
+                    math::lerp(from_position[1], to_position[1], t),
+                    math::lerp(from_position[2], to_position[2], t)
+                ],
+                &math::quat_nlerp(&from_rotation, &to_rotation, t),
+                &[
+                    math::lerp(from_scale[0], to_scale[0], t),
+                    math::lerp(from_scale[1], to_scale[1], t),
+                    math::lerp(from_scale[2], to_scale[2], t)
+                ]
+            );
+
+            let mut view = mat4::new_identity();
+            mat4::inverse(&mut view, &world);
+            Some(view)
+        } else {
+            Some(*active_camera.get_view())
+        }
+    }
+    pub fn get_active_projection(&mut self) -> Option<[f32; 16]> {
+        let mut active_camera = match self.get_active_camera() {
+            Some(active_camera) => active_camera,
+            None => return None,
+        };
+
+        if let Some(ref mut transition) = self.data.transition {
+            let t = transition.easing.ease(transition.elapsed / transition.duration);
+            let ref mut from = transition.from;
+            let mut projection = mat4::new_identity();
+
+            if from.get_orthographic_mode() != active_camera.get_orthographic_mode() {
+                let from_projection = *from.get_projection();
+                let to_projection = active_camera.get_projection();
+
+                for i in 0..16 {
+                    projection[i] = math::lerp(from_projection[i], to_projection[i], t);
+                }
+            } else {
+                let (from_aspect, from_scale) = from.get_projection_aspect();
+                let (to_aspect, to_scale) = active_camera.get_projection_aspect();
+                let aspect = math::lerp(from_aspect, to_aspect, t);
+                let scale = math::lerp(from_scale, to_scale, t);
+                let near = math::lerp(from.get_near(), active_camera.get_near(), t);
+                let far = math::lerp(from.get_far(), active_camera.get_far(), t);
+
+                if active_camera.get_orthographic_mode() {
+                    let top = math::lerp(from.get_orthographic_size(), active_camera.get_orthographic_size(), t) * scale;
+                    let right = top * aspect;
+
+                    mat4::orthographic(&mut projection, -right, right, top, -top, near, far);
+                } else {
+                    let fov = math::lerp(from.get_fov(), active_camera.get_fov(), t);
+
+                    mat4::perspective(&mut projection, fov.to_radians(), aspect, near, far);
+                    projection[0] /= scale;
+                    projection[5] /= scale;
+                }
+
+                if active_camera.get_reversed_z() {
+                    projection[2] = -projection[2];
+                    projection[6] = -projection[6];
+                    projection[10] = -projection[10];
+                    projection[14] = -projection[14];
+                }
+            }
+
+            Some(projection)
+        } else {
+            Some(*active_camera.get_projection())
+        }
+    }
 }
 
 impl ComponentManager for Camera3DManager {
@@ -67,12 +218,16 @@ impl ComponentManager for Camera3DManager {
 
     fn get_order(&self) -> usize { 0 }
     fn is_empty(&self) -> bool {
-        self.data.components == 0usize
+        self.data.cameras.is_empty()
     }
 
     fn clear(&mut self) {}
     fn init(&mut self) {}
-    fn update(&mut self) {}
+    fn update(&mut self) {
+        for camera in self.data.cameras.iter_mut() {
+            camera.__end_frame();
+        }
+    }
 
     fn add_component(&mut self, component: &mut Box<Component>) {
         let ref mut component = component.downcast_mut::<Camera3D>().unwrap();
@@ -83,12 +238,15 @@ impl ComponentManager for Camera3DManager {
             self.set_active_camera(component);
         }
 
-        self.data.components += 1;
+        self.data.cameras.push(component.clone());
     }
     fn remove_component(&mut self, component: &mut Box<Component>) {
         let mut component = component.downcast_mut::<Camera3D>().unwrap();
 
-        self.data.components -= 1;
+        let index = self.data.cameras.iter().position(|camera| camera == &*component);
+        if let Some(index) = index {
+            self.data.cameras.remove(index);
+        }
 
         if component.active() {
             component.__set_manager(None);
@@ -0,0 +1,348 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use shared::Shared;
+use to_radians::ToRadians;
+use transform_components::Transform3D;
+use scene_graph::{Entity, Component, ComponentManager, Id};
+
+use camera3d::Camera3D;
+use camera3d_path_manager::Camera3DPathManager;
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathInterpolation {
+    Linear,
+    CatmullRom,
+    Bezier,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathPlayback {
+    Stopped,
+    Playing,
+    Paused,
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    pub time: f32,
+    pub position: [f32; 3],
+    pub in_control: [f32; 3],
+    pub out_control: [f32; 3],
+    pub target: [f32; 3],
+    pub fov: f32,
+    pub roll: f32,
+}
+
+impl CameraKeyframe {
+    pub fn new(time: f32, position: &[f32; 3], target: &[f32; 3], fov: f32, roll: f32) -> Self {
+        CameraKeyframe {
+            time: time,
+            position: *position,
+            in_control: *position,
+            out_control: *position,
+            target: *target,
+            fov: fov,
+            roll: roll,
+        }
+    }
+    pub fn with_controls(mut self, in_control: &[f32; 3], out_control: &[f32; 3]) -> Self {
+        self.in_control = *in_control;
+        self.out_control = *out_control;
+        self
+    }
+}
+
+
+struct Camera3DPathData {
+
+    entity: Option<Entity>,
+    path_manager: Option<Camera3DPathManager>,
+
+    keyframes: Vec<CameraKeyframe>,
+    interpolation: PathInterpolation,
+    up: [f32; 3],
+
+    time: f32,
+    looping: bool,
+    playback: PathPlayback,
+}
+
+
+#[derive(Clone)]
+pub struct Camera3DPath {
+    data: Shared<Camera3DPathData>,
+}
+
+impl Camera3DPath {
+    pub fn new() -> Self {
+        Camera3DPath {
+            data: Shared::new(Camera3DPathData {
+
+                entity: None,
+                path_manager: None,
+
+                keyframes: Vec::new(),
+                interpolation: PathInterpolation::CatmullRom,
+                up: [0f32, 1f32, 0f32],
+
+                time: 0f32,
+                looping: false,
+                playback: PathPlayback::Stopped,
+            })
+        }
+    }
+
+    pub fn get_manager(&self) -> Option<Camera3DPathManager> {
+        self.data.path_manager.clone()
+    }
+    pub fn __set_manager(&mut self, path_manager: Option<Camera3DPathManager>) {
+        self.data.path_manager = path_manager;
+    }
+
+    pub fn add_keyframe(&mut self, keyframe: CameraKeyframe) -> &mut Self {
+        {
+            let ref mut keyframes = self.data.keyframes;
+            let index = keyframes.iter().position(|k| k.time > keyframe.time).unwrap_or(keyframes.len());
+            keyframes.insert(index, keyframe);
+        }
+        self
+    }
+    pub fn clear_keyframes(&mut self) -> &mut Self {
+        self.data.keyframes.clear();
+        self
+    }
+    pub fn get_keyframes(&self) -> &[CameraKeyframe] {
+        &self.data.keyframes
+    }
+
+    pub fn get_interpolation(&self) -> PathInterpolation {
+        self.data.interpolation
+    }
+    pub fn set_interpolation(&mut self, interpolation: PathInterpolation) -> &mut Self {
+        self.data.interpolation = interpolation;
+        self
+    }
+
+    pub fn get_up(&self) -> &[f32; 3] {
+        &self.data.up
+    }
+    pub fn set_up(&mut self, up: &[f32; 3]) -> &mut Self {
+        self.data.up = *up;
+        self
+    }
+
+    pub fn get_looping(&self) -> bool {
+        self.data.looping
+    }
+    pub fn set_looping(&mut self, looping: bool) -> &mut Self {
+        self.data.looping = looping;
+        self
+    }
+
+    pub fn get_duration(&self) -> f32 {
+        match self.data.keyframes.last() {
+            Some(keyframe) => keyframe.time,
+            None => 0f32,
+        }
+    }
+    pub fn get_time(&self) -> f32 {
+        self.data.time
+    }
+    pub fn get_playback(&self) -> PathPlayback {
+        self.data.playback
+    }
+    pub fn is_playing(&self) -> bool {
+        self.data.playback == PathPlayback::Playing
+    }
+
+    pub fn play(&mut self) -> &mut Self {
+        self.data.playback = PathPlayback::Playing;
+        self
+    }
+    pub fn pause(&mut self) -> &mut Self {
+        if self.data.playback == PathPlayback::Playing {
+            self.data.playback = PathPlayback::Paused;
+        }
+        self
+    }
+    pub fn stop(&mut self) -> &mut Self {
+        self.data.playback = PathPlayback::Stopped;
+        self.data.time = 0f32;
+        self
+    }
+    pub fn seek(&mut self, time: f32) -> &mut Self {
+        let duration = self.get_duration();
+        self.data.time = if time < 0f32 {0f32} else if time > duration {duration} else {time};
+        self.apply();
+        self
+    }
+
+    pub fn update(&mut self, delta: f32) -> &mut Self {
+        if self.data.playback == PathPlayback::Playing {
+            let duration = self.get_duration();
+            let mut time = self.data.time + delta;
+
+            if time >= duration {
+                if self.data.looping && duration > 0f32 {
+                    time = time % duration;
+                } else {
+                    time = duration;
+                    self.data.playback = PathPlayback::Stopped;
+                }
+            }
+
+            self.data.time = time;
+            self.apply();
+        }
+        self
+    }
+
+    pub fn sample(&self, time: f32) -> Option<CameraKeyframe> {
+        let ref keyframes = self.data.keyframes;
+        let count = keyframes.len();
+
+        if count == 0 {
+            return None;
+        }
+
+        let index = keyframes.iter().position(|k| k.time > time).unwrap_or(count);
+
+        if index == 0 {
+            return Some(keyframes[0]);
+        } else if index == count {
+            return Some(keyframes[count - 1]);
+        }
+
+        let ref a = keyframes[index - 1];
+        let ref b = keyframes[index];
+        let span = b.time - a.time;
+        let t = if span > 0f32 {(time - a.time) / span} else {1f32};
+
+        let (position, target) = match self.data.interpolation {
+            PathInterpolation::Linear => (
+                lerp3(&a.position, &b.position, t),
+                lerp3(&a.target, &b.target, t)
+            ),
+            PathInterpolation::CatmullRom => {
+                let ref p = keyframes[if index > 1 {index - 2} else {0}];
+                let ref n = keyframes[if index + 1 < count {index + 1} else {count - 1}];
+                (
+                    catmull_rom3(&p.position, &a.position, &b.position, &n.position, t),
+                    catmull_rom3(&p.target, &a.target, &b.target, &n.target, t)
+                )
+            },
+            PathInterpolation::Bezier => (
+                bezier3(&a.position, &a.out_control, &b.in_control, &b.position, t),
+                lerp3(&a.target, &b.target, t)
+            ),
+        };
+
+        Some(CameraKeyframe {
+            time: time,
+            position: position,
+            in_control: position,
+            out_control: position,
+            target: target,
+            fov: math::lerp(a.fov, b.fov, t),
+            roll: math::lerp(a.roll, b.roll, t),
+        })
+    }
+
+    fn apply(&mut self) {
+        let keyframe = match self.sample(self.data.time) {
+            Some(keyframe) => keyframe,
+            None => return,
+        };
+        let entity = match self.get_entity() {
+            Some(entity) => entity,
+            None => return,
+        };
+
+        if let Some(ref mut transform3d) = entity.get_component::<Transform3D>() {
+            let up = self.data.up;
+            let world = *transform3d.get_world_matrix();
+            let local = *transform3d.get_matrix();
+
+            if let Some(b) = math::look_basis(&keyframe.position, &keyframe.target, &up) {
+                let roll = keyframe.roll.to_radians();
+                let c = math::cos(roll);
+                let s = math::sin(roll);
+
+                let basis = [
+                    b[0] * c + b[3] * s, b[1] * c + b[4] * s, b[2] * c + b[5] * s,
+                    b[3] * c - b[0] * s, b[4] * c - b[1] * s, b[5] * c - b[2] * s,
+                    b[6], b[7], b[8]
+                ];
+
+                transform3d.set_rotation(&math::local_rotation(&world, &local, &basis));
+            }
+            transform3d.set_position(&math::local_position(&world, &local, &keyframe.position));
+        }
+        if let Some(ref mut camera3d) = entity.get_component::<Camera3D>() {
+            camera3d.set_fov(keyframe.fov);
+        }
+    }
+}
+
+fn lerp3(a: &[f32; 3], b: &[f32; 3], t: f32) -> [f32; 3] {
+    [math::lerp(a[0], b[0], t), math::lerp(a[1], b[1], t), math::lerp(a[2], b[2], t)]
+}
+fn catmull_rom3(p0: &[f32; 3], p1: &[f32; 3], p2: &[f32; 3], p3: &[f32; 3], t: f32) -> [f32; 3] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let mut out = [0f32; 3];
+
+    for i in 0..3 {
+        out[i] = 0.5f32 * (
+            2f32 * p1[i] +
+            (p2[i] - p0[i]) * t +
+            (2f32 * p0[i] - 5f32 * p1[i] + 4f32 * p2[i] - p3[i]) * t2 +
+            (3f32 * p1[i] - p0[i] - 3f32 * p2[i] + p3[i]) * t3
+        );
+    }
+    out
+}
+fn bezier3(p0: &[f32; 3], p1: &[f32; 3], p2: &[f32; 3], p3: &[f32; 3], t: f32) -> [f32; 3] {
+    let u = 1f32 - t;
+    let a = u * u * u;
+    let b = 3f32 * u * u * t;
+    let c = 3f32 * u * t * t;
+    let d = t * t * t;
+    let mut out = [0f32; 3];
+
+    for i in 0..3 {
+        out[i] = a * p0[i] + b * p1[i] + c * p2[i] + d * p3[i];
+    }
+    out
+}
+
+impl Component for Camera3DPath {
+    fn get_id(&self) -> Id {
+        Id::of::<Camera3DPath>()
+    }
+    fn new_component_manager(&self) -> Box<ComponentManager> {
+        Box::new(Camera3DPathManager::new())
+    }
+    fn get_component_manager_id(&self) -> Id {
+        Id::of::<Camera3DPathManager>()
+    }
+    fn get_entity(&self) -> Option<Entity> {
+        self.data.entity.clone()
+    }
+    fn set_entity(&mut self, entity: Option<Entity>) {
+        self.data.entity = entity;
+    }
+}
+
+impl PartialEq<Camera3DPath> for Camera3DPath {
+    fn eq(&self, other: &Camera3DPath) -> bool {
+        (&*self.data as *const _) == (&*other.data as *const _)
+    }
+    fn ne(&self, other: &Camera3DPath) -> bool {
+        !self.eq(other)
+    }
+}
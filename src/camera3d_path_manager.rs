@@ -0,0 +1,69 @@
+use alloc::boxed::Box;
+
+use shared::Shared;
+use scene_graph::{Scene, Component, ComponentManager, Id};
+
+use camera3d_path::Camera3DPath;
+
+
+struct Camera3DPathManagerData {
+    scene: Option<Scene>,
+    components: usize,
+}
+
+
+#[derive(Clone)]
+pub struct Camera3DPathManager {
+    data: Shared<Camera3DPathManagerData>,
+}
+
+impl Camera3DPathManager {
+
+    pub fn new() -> Camera3DPathManager {
+        Camera3DPathManager {
+            data: Shared::new(Camera3DPathManagerData {
+                scene: None,
+                components: 0usize,
+            })
+        }
+    }
+}
+
+impl ComponentManager for Camera3DPathManager {
+
+    fn get_id(&self) -> Id { Id::of::<Camera3DPathManager>() }
+
+    fn get_scene(&self) -> Option<Scene> {
+        match self.data.scene {
+            Some(ref scene) => Some(scene.clone()),
+            None => None,
+        }
+    }
+    fn set_scene(&mut self, scene: Option<Scene>) {
+        self.data.scene = scene;
+    }
+
+    fn get_order(&self) -> usize { 0 }
+    fn is_empty(&self) -> bool {
+        self.data.components == 0usize
+    }
+
+    fn clear(&mut self) {}
+    fn init(&mut self) {}
+    fn update(&mut self) {}
+
+    fn add_component(&mut self, component: &mut Box<Component>) {
+        let ref mut component = component.downcast_mut::<Camera3DPath>().unwrap();
+
+        component.__set_manager(Some(self.clone()));
+
+        self.data.components += 1;
+    }
+    fn remove_component(&mut self, component: &mut Box<Component>) {
+        let mut component = component.downcast_mut::<Camera3DPath>().unwrap();
+
+        self.data.components -= 1;
+
+        component.__set_manager(None);
+    }
+}
@@ -0,0 +1,40 @@
+use core::fmt;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraError {
+    InvalidWidth(usize),
+    InvalidHeight(usize),
+    InvalidRenderTargetSize(usize, usize),
+    InvalidTargetAspect(f32),
+    InvalidFov(f32),
+    InvalidNear(f32, f32),
+    InvalidFar(f32, f32),
+    InvalidOrthographicSize(f32),
+    InvalidPixelsPerUnit(f32),
+}
+
+impl fmt::Display for CameraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CameraError::InvalidWidth(width) =>
+                write!(f, "invalid camera width {}, width must be greater than 0", width),
+            CameraError::InvalidHeight(height) =>
+                write!(f, "invalid camera height {}, height must be greater than 0", height),
+            CameraError::InvalidRenderTargetSize(width, height) =>
+                write!(f, "invalid camera size {}x{}, size must match the render target size", width, height),
+            CameraError::InvalidTargetAspect(target_aspect) =>
+                write!(f, "invalid camera target aspect {}, aspect must be greater than 0", target_aspect),
+            CameraError::InvalidFov(fov) =>
+                write!(f, "invalid camera fov {}, fov must be between 0 and 180 degrees", fov),
+            CameraError::InvalidNear(near, far) =>
+                write!(f, "invalid camera near {}, near must be less than far {} and greater than 0 for perspective", near, far),
+            CameraError::InvalidFar(near, far) =>
+                write!(f, "invalid camera far {}, far must be greater than near {}", far, near),
+            CameraError::InvalidOrthographicSize(orthographic_size) =>
+                write!(f, "invalid camera orthographic size {}, size must be greater than 0", orthographic_size),
+            CameraError::InvalidPixelsPerUnit(pixels_per_unit) =>
+                write!(f, "invalid pixels per unit {}, pixels per unit must be greater than 0", pixels_per_unit),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use camera3d::Camera3D;
+use camera3d_desc::Camera3DDesc;
+use camera2d::Camera2D;
+use camera2d_desc::Camera2DDesc;
+use pixel_perfect::PixelPerfect;
+
+
+#[derive(Deserialize)]
+struct PixelPerfectFields {
+    reference_width: usize,
+    reference_height: usize,
+    pixels_per_unit: f32,
+}
+
+
+impl Serialize for Camera3D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.describe().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Camera3D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Camera3DDesc::deserialize(deserializer).and_then(|desc| desc.try_build().map_err(D::Error::custom))
+    }
+}
+
+impl Serialize for Camera2D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.describe().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Camera2D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Camera2DDesc::deserialize(deserializer).and_then(|desc| desc.try_build().map_err(D::Error::custom))
+    }
+}
+
+impl<'de> Deserialize<'de> for PixelPerfect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PixelPerfectFields::deserialize(deserializer).and_then(|fields| {
+            PixelPerfect::try_new(fields.reference_width, fields.reference_height, fields.pixels_per_unit)
+                .map_err(D::Error::custom)
+        })
+    }
+}
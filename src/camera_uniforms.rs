@@ -0,0 +1,88 @@
+use core::{mem, slice};
+
+use mat4;
+
+use camera3d::Camera3D;
+use camera2d::Camera2D;
+
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraUniforms {
+    pub view: [f32; 16],
+    pub projection: [f32; 16],
+    pub view_projection: [f32; 16],
+    pub inv_view: [f32; 16],
+    pub inv_projection: [f32; 16],
+    pub inv_view_projection: [f32; 16],
+    pub position: [f32; 4],
+    pub near: f32,
+    pub far: f32,
+    pub time: f32,
+    _padding: f32,
+    pub size: [f32; 2],
+    pub inv_size: [f32; 2],
+}
+
+impl CameraUniforms {
+    pub fn from_camera3d(camera: &mut Camera3D, time: f32) -> Self {
+        let view = *camera.get_view();
+        let projection = *camera.get_projection();
+
+        CameraUniforms::from_matrices(
+            &view, &projection,
+            camera.get_near(), camera.get_far(), time,
+            &[camera.get_width() as f32, camera.get_height() as f32],
+            &[camera.get_inv_width(), camera.get_inv_height()]
+        )
+    }
+    pub fn from_camera2d(camera: &mut Camera2D, time: f32) -> Self {
+        let view = *camera.get_view3d();
+        let projection = *camera.get_projection3d();
+
+        CameraUniforms::from_matrices(
+            &view, &projection,
+            camera.get_near(), camera.get_far(), time,
+            &[camera.get_width() as f32, camera.get_height() as f32],
+            &[camera.get_inv_width(), camera.get_inv_height()]
+        )
+    }
+
+    fn from_matrices(
+        view: &[f32; 16], projection: &[f32; 16],
+        near: f32, far: f32, time: f32, size: &[f32; 2], inv_size: &[f32; 2]
+    ) -> Self {
+        let mut uniforms = CameraUniforms {
+            view: *view,
+            projection: *projection,
+            view_projection: mat4::new_identity(),
+            inv_view: mat4::new_identity(),
+            inv_projection: mat4::new_identity(),
+            inv_view_projection: mat4::new_identity(),
+            position: [0f32, 0f32, 0f32, 1f32],
+            near: near,
+            far: far,
+            time: time,
+            _padding: 0f32,
+            size: *size,
+            inv_size: *inv_size,
+        };
+
+        mat4::mul(&mut uniforms.view_projection, projection, view);
+        mat4::inverse(&mut uniforms.inv_view, view);
+        mat4::inverse(&mut uniforms.inv_projection, projection);
+        mat4::inverse(&mut uniforms.inv_view_projection, &uniforms.view_projection);
+
+        uniforms.position[0] = uniforms.inv_view[12];
+        uniforms.position[1] = uniforms.inv_view[13];
+        uniforms.position[2] = uniforms.inv_view[14];
+
+        uniforms
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self as *const CameraUniforms as *const u8, mem::size_of::<CameraUniforms>())
+        }
+    }
+}
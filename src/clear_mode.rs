@@ -0,0 +1,36 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClearMode {
+    SolidColor,
+    Skybox(usize),
+    DepthOnly,
+    Nothing,
+}
+
+impl ClearMode {
+    pub fn clears_color(&self) -> bool {
+        match *self {
+            ClearMode::SolidColor | ClearMode::Skybox(_) => true,
+            _ => false,
+        }
+    }
+    pub fn clears_depth(&self) -> bool {
+        *self != ClearMode::Nothing
+    }
+    pub fn get_skybox(&self) -> Option<usize> {
+        match *self {
+            ClearMode::Skybox(id) => Some(id),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ClearMode {
+    fn default() -> Self {
+        ClearMode::SolidColor
+    }
+}
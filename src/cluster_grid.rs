@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use mat4;
+
+use camera3d::Camera3D;
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClusterBounds {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClusterGrid {
+    dimensions: [usize; 3],
+    near: f32,
+    far: f32,
+    depths: Vec<f32>,
+    clusters: Vec<ClusterBounds>,
+}
+
+impl ClusterGrid {
+    pub fn new(camera: &mut Camera3D, x: usize, y: usize, z: usize) -> Self {
+        let x = if x > 0usize {x} else {1usize};
+        let y = if y > 0usize {y} else {1usize};
+        let z = if z > 0usize {z} else {1usize};
+
+        let near = camera.get_near();
+        let far = camera.get_far();
+
+        let mut inv_projection = mat4::new_identity();
+        mat4::inverse(&mut inv_projection, camera.get_unjittered_projection());
+
+        let mut depths = Vec::with_capacity(z + 1usize);
+        for k in 0..(z + 1usize) {
+            depths.push(near * math::pow(far / near, k as f32 / z as f32));
+        }
+
+        let mut clusters = Vec::with_capacity(x * y * z);
+
+        for k in 0..z {
+            let d0 = depths[k];
+            let d1 = depths[k + 1usize];
+
+            for j in 0..y {
+                let y0 = (j as f32 / y as f32) * 2f32 - 1f32;
+                let y1 = ((j + 1usize) as f32 / y as f32) * 2f32 - 1f32;
+
+                for i in 0..x {
+                    let x0 = (i as f32 / x as f32) * 2f32 - 1f32;
+                    let x1 = ((i + 1usize) as f32 / x as f32) * 2f32 - 1f32;
+
+                    let mut bounds = ClusterBounds {
+                        min: [::core::f32::INFINITY; 3],
+                        max: [::core::f32::NEG_INFINITY; 3],
+                    };
+
+                    for &(nx, ny) in [(x0, y0), (x1, y0), (x1, y1), (x0, y1)].iter() {
+                        for &d in [d0, d1].iter() {
+                            let p = unproject_at_depth(&inv_projection, nx, ny, d);
+
+                            for a in 0..3 {
+                                if p[a] < bounds.min[a] {
+                                    bounds.min[a] = p[a];
+                                }
+                                if p[a] > bounds.max[a] {
+                                    bounds.max[a] = p[a];
+                                }
+                            }
+                        }
+                    }
+
+                    clusters.push(bounds);
+                }
+            }
+        }
+
+        ClusterGrid {
+            dimensions: [x, y, z],
+            near: near,
+            far: far,
+            depths: depths,
+            clusters: clusters,
+        }
+    }
+
+    pub fn get_dimensions(&self) -> &[usize; 3] {
+        &self.dimensions
+    }
+    pub fn get_depths(&self) -> &[f32] {
+        &self.depths
+    }
+    pub fn get_clusters(&self) -> &[ClusterBounds] {
+        &self.clusters
+    }
+
+    pub fn get_index(&self, x: usize, y: usize, z: usize) -> usize {
+        x + y * self.dimensions[0] + z * self.dimensions[0] * self.dimensions[1]
+    }
+    pub fn get_cluster(&self, x: usize, y: usize, z: usize) -> Option<&ClusterBounds> {
+        if x < self.dimensions[0] && y < self.dimensions[1] && z < self.dimensions[2] {
+            self.clusters.get(self.get_index(x, y, z))
+        } else {
+            None
+        }
+    }
+    pub fn get_slice(&self, depth: f32) -> Option<usize> {
+        if depth < self.near || depth > self.far {
+            None
+        } else {
+            let z = self.dimensions[2];
+            let slice = math::floor(math::ln(depth / self.near) / math::ln(self.far / self.near) * z as f32) as usize;
+            Some(if slice < z {slice} else {z - 1usize})
+        }
+    }
+}
+
+fn unproject_at_depth(inv_projection: &[f32; 16], x: f32, y: f32, depth: f32) -> [f32; 3] {
+    let a = math::transform_mat4_point(inv_projection, &[x, y, -1f32]);
+    let b = math::transform_mat4_point(inv_projection, &[x, y, 1f32]);
+    let t = (-depth - a[2]) / (b[2] - a[2]);
+
+    [
+        math::lerp(a[0], b[0], t),
+        math::lerp(a[1], b[1], t),
+        -depth
+    ]
+}
@@ -0,0 +1,46 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SmoothStep,
+}
+
+impl Easing {
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = if t < 0f32 {0f32} else if t > 1f32 {1f32} else {t};
+
+        match *self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2f32 - t),
+            Easing::QuadInOut => if t < 0.5f32 {
+                2f32 * t * t
+            } else {
+                -1f32 + (4f32 - 2f32 * t) * t
+            },
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let u = t - 1f32;
+                u * u * u + 1f32
+            },
+            Easing::CubicInOut => if t < 0.5f32 {
+                4f32 * t * t * t
+            } else {
+                let u = 2f32 * t - 2f32;
+                0.5f32 * u * u * u + 1f32
+            },
+            Easing::SmoothStep => t * t * (3f32 - 2f32 * t),
+        }
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Linear
+    }
+}
@@ -0,0 +1,54 @@
+use math;
+
+
+pub const FRUSTUM_EDGES: [[usize; 2]; 12] = [
+    [0, 1], [1, 2], [2, 3], [3, 0],
+    [4, 5], [5, 6], [6, 7], [7, 4],
+    [0, 4], [1, 5], [2, 6], [3, 7],
+];
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrustumSlice {
+    pub near: f32,
+    pub far: f32,
+    pub corners: [[f32; 3]; 8],
+    pub center: [f32; 3],
+    pub radius: f32,
+}
+
+impl FrustumSlice {
+    pub fn new(near: f32, far: f32, corners: [[f32; 3]; 8]) -> Self {
+        let mut center = [0f32; 3];
+
+        for corner in corners.iter() {
+            center[0] += corner[0] * 0.125f32;
+            center[1] += corner[1] * 0.125f32;
+            center[2] += corner[2] * 0.125f32;
+        }
+
+        let mut radius = 0f32;
+
+        for corner in corners.iter() {
+            let d = math::length3(corner[0] - center[0], corner[1] - center[1], corner[2] - center[2]);
+            if d > radius {
+                radius = d;
+            }
+        }
+
+        FrustumSlice {
+            near: near,
+            far: far,
+            corners: corners,
+            center: center,
+            radius: radius,
+        }
+    }
+
+    pub fn get_near_corners(&self) -> &[[f32; 3]] {
+        &self.corners[0..4]
+    }
+    pub fn get_far_corners(&self) -> &[[f32; 3]] {
+        &self.corners[4..8]
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::boxed::Box;
+
+use shared::Shared;
+use scene_graph::{Entity, Component, ComponentManager, Id};
+
+use layer_manager::LayerManager;
+
+
+pub const DEFAULT_LAYER: u32 = 1u32;
+pub const ALL_LAYERS: u32 = 0xffffffffu32;
+pub const LAYER_COUNT: usize = 32usize;
+
+
+struct LayerData {
+    entity: Option<Entity>,
+    mask: u32,
+}
+
+
+#[derive(Clone)]
+pub struct Layer {
+    data: Shared<LayerData>,
+}
+
+impl Layer {
+    pub fn new() -> Self {
+        Layer {
+            data: Shared::new(LayerData {
+                entity: None,
+                mask: DEFAULT_LAYER,
+            })
+        }
+    }
+    pub fn new_with_mask(mask: u32) -> Self {
+        let mut layer = Layer::new();
+        layer.set_mask(mask);
+        layer
+    }
+
+    pub fn get_mask(&self) -> u32 {
+        self.data.mask
+    }
+    pub fn set_mask(&mut self, mask: u32) -> &mut Self {
+        self.data.mask = mask;
+        self
+    }
+
+    pub fn add_layer(&mut self, index: usize) -> &mut Self {
+        self.data.mask |= layer_bit(index);
+        self
+    }
+    pub fn remove_layer(&mut self, index: usize) -> &mut Self {
+        self.data.mask &= !layer_bit(index);
+        self
+    }
+    pub fn has_layer(&self, index: usize) -> bool {
+        (self.data.mask & layer_bit(index)) != 0u32
+    }
+}
+
+fn layer_bit(index: usize) -> u32 {
+    if index < LAYER_COUNT {1u32 << index} else {0u32}
+}
+
+pub fn get_entity_layer_mask(entity: &Entity) -> u32 {
+    match entity.get_component::<Layer>() {
+        Some(layer) => layer.get_mask(),
+        None => DEFAULT_LAYER,
+    }
+}
+
+impl Component for Layer {
+    fn get_id(&self) -> Id {
+        Id::of::<Layer>()
+    }
+    fn new_component_manager(&self) -> Box<ComponentManager> {
+        Box::new(LayerManager::new())
+    }
+    fn get_component_manager_id(&self) -> Id {
+        Id::of::<LayerManager>()
+    }
+    fn get_entity(&self) -> Option<Entity> {
+        self.data.entity.clone()
+    }
+    fn set_entity(&mut self, entity: Option<Entity>) {
+        self.data.entity = entity;
+    }
+}
+
+impl PartialEq<Layer> for Layer {
+    fn eq(&self, other: &Layer) -> bool {
+        (&*self.data as *const _) == (&*other.data as *const _)
+    }
+    fn ne(&self, other: &Layer) -> bool {
+        !self.eq(other)
+    }
+}
@@ -1,4 +1,5 @@
 #![feature(alloc)]
+#![feature(core_intrinsics)]
 #![no_std]
 
 
@@ -11,15 +12,68 @@ extern crate mat4;
 extern crate scene_graph;
 extern crate transform_components;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
+
+mod camera_error;
+mod easing;
+mod math;
+mod render_target;
+mod clear_mode;
 
 mod camera3d;
 mod camera3d_manager;
+mod camera3d_desc;
 
 mod camera2d;
 mod camera2d_manager;
+mod camera2d_desc;
+
+mod aspect_policy;
+mod axonometric;
+mod pixel_perfect;
+mod parallax;
+mod presets;
+mod camera_uniforms;
+mod frustum;
+mod shadow_cascades;
+mod cluster_grid;
+
+mod layer;
+mod layer_manager;
+
+#[cfg(feature = "serde")]
+mod camera_serde;
+
+mod camera3d_path;
+mod camera3d_path_manager;
+
+pub use camera_error::CameraError;
+pub use easing::Easing;
+pub use render_target::{RenderTarget, RenderTargetKind, PixelFormat};
+pub use clear_mode::ClearMode;
 
 pub use camera3d::Camera3D;
 pub use camera3d_manager::Camera3DManager;
+pub use camera3d_desc::Camera3DDesc;
 
 pub use camera2d::Camera2D;
 pub use camera2d_manager::Camera2DManager;
+pub use camera2d_desc::Camera2DDesc;
+
+pub use aspect_policy::{AspectPolicy, AspectFit};
+pub use axonometric::Axonometric;
+pub use pixel_perfect::PixelPerfect;
+pub use parallax::ParallaxLayer;
+pub use presets::{Camera3DPreset, Camera2DPreset};
+pub use camera_uniforms::CameraUniforms;
+pub use frustum::{FrustumSlice, FRUSTUM_EDGES};
+pub use shadow_cascades::{ShadowCascade, compute_cascade_splits};
+pub use cluster_grid::{ClusterGrid, ClusterBounds};
+
+pub use layer::{Layer, DEFAULT_LAYER, ALL_LAYERS, LAYER_COUNT};
+pub use layer_manager::LayerManager;
+
+pub use camera3d_path::{Camera3DPath, CameraKeyframe, PathInterpolation, PathPlayback};
+pub use camera3d_path_manager::Camera3DPathManager;
@@ -0,0 +1,294 @@
+use core::intrinsics;
+
+use mat4;
+
+
+pub fn sqrt(x: f32) -> f32 {
+    unsafe { intrinsics::sqrtf32(x) }
+}
+pub fn sin(x: f32) -> f32 {
+    unsafe { intrinsics::sinf32(x) }
+}
+pub fn cos(x: f32) -> f32 {
+    unsafe { intrinsics::cosf32(x) }
+}
+pub fn tan(x: f32) -> f32 {
+    sin(x) / cos(x)
+}
+pub fn pow(x: f32, y: f32) -> f32 {
+    unsafe { intrinsics::powf32(x, y) }
+}
+pub fn ln(x: f32) -> f32 {
+    unsafe { intrinsics::logf32(x) }
+}
+pub fn floor(x: f32) -> f32 {
+    unsafe { intrinsics::floorf32(x) }
+}
+pub fn abs(x: f32) -> f32 {
+    unsafe { intrinsics::fabsf32(x) }
+}
+pub fn ceil(x: f32) -> f32 {
+    unsafe { intrinsics::ceilf32(x) }
+}
+pub fn round(x: f32) -> f32 {
+    unsafe { intrinsics::roundf32(x) }
+}
+
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+pub fn halton(index: usize, base: usize) -> f32 {
+    let mut result = 0f32;
+    let mut f = 1f32;
+    let mut i = index;
+    let b = base as f32;
+
+    while i > 0usize {
+        f = f / b;
+        result += f * (i % base) as f32;
+        i = i / base;
+    }
+    result
+}
+
+pub fn length2(x: f32, y: f32) -> f32 {
+    sqrt(x * x + y * y)
+}
+pub fn length3(x: f32, y: f32, z: f32) -> f32 {
+    sqrt(x * x + y * y + z * z)
+}
+
+pub fn normalize3(v: &[f32; 3]) -> [f32; 3] {
+    let l = length3(v[0], v[1], v[2]);
+    if l > 0f32 {[v[0] / l, v[1] / l, v[2] / l]} else {*v}
+}
+pub fn dot3(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+pub fn cross3(a: &[f32; 3], b: &[f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0]
+    ]
+}
+
+pub fn look_basis(eye: &[f32; 3], target: &[f32; 3], up: &[f32; 3]) -> Option<[f32; 9]> {
+    let forward = [eye[0] - target[0], eye[1] - target[1], eye[2] - target[2]];
+
+    if length3(forward[0], forward[1], forward[2]) < 0.000001f32 {
+        return None;
+    }
+
+    let z = normalize3(&forward);
+    let mut x = cross3(up, &z);
+
+    if length3(x[0], x[1], x[2]) < 0.000001f32 {
+        let fallback = if abs(z[2]) < 0.99f32 {[0f32, 0f32, 1f32]} else {[1f32, 0f32, 0f32]};
+        x = cross3(&fallback, &z);
+    }
+
+    let x = normalize3(&x);
+    let y = cross3(&z, &x);
+
+    Some([
+        x[0], x[1], x[2],
+        y[0], y[1], y[2],
+        z[0], z[1], z[2]
+    ])
+}
+pub fn inverse_parent(world: &[f32; 16], local: &[f32; 16]) -> [f32; 16] {
+    let mut inv_world = mat4::new_identity();
+    let mut inv_parent = mat4::new_identity();
+
+    mat4::inverse(&mut inv_world, world);
+    mat4::mul(&mut inv_parent, local, &inv_world);
+    inv_parent
+}
+pub fn local_position(world: &[f32; 16], local: &[f32; 16], position: &[f32; 3]) -> [f32; 3] {
+    transform_mat4_point(&inverse_parent(world, local), position)
+}
+pub fn local_rotation(world: &[f32; 16], local: &[f32; 16], basis: &[f32; 9]) -> [f32; 4] {
+    let mut m = mat4::new_identity();
+
+    mat4::mul(&mut m, &inverse_parent(world, local), &[
+        basis[0], basis[1], basis[2], 0f32,
+        basis[3], basis[4], basis[5], 0f32,
+        basis[6], basis[7], basis[8], 0f32,
+        0f32, 0f32, 0f32, 1f32
+    ]);
+
+    decompose_mat4(&m).1
+}
+
+pub fn look_at(out: &mut [f32; 16], eye: &[f32; 3], target: &[f32; 3], up: &[f32; 3]) {
+    let b = look_basis(eye, target, up).unwrap_or([
+        1f32, 0f32, 0f32,
+        0f32, 1f32, 0f32,
+        0f32, 0f32, 1f32
+    ]);
+    let x = [b[0], b[1], b[2]];
+    let y = [b[3], b[4], b[5]];
+    let z = [b[6], b[7], b[8]];
+
+    out[0] = x[0];
+    out[1] = y[0];
+    out[2] = z[0];
+    out[3] = 0f32;
+    out[4] = x[1];
+    out[5] = y[1];
+    out[6] = z[1];
+    out[7] = 0f32;
+    out[8] = x[2];
+    out[9] = y[2];
+    out[10] = z[2];
+    out[11] = 0f32;
+    out[12] = -dot3(&x, eye);
+    out[13] = -dot3(&y, eye);
+    out[14] = -dot3(&z, eye);
+    out[15] = 1f32;
+}
+
+pub fn mat4_from_mat32(out: &mut [f32; 16], m: &[f32; 6]) {
+    out[0] = m[0];
+    out[1] = m[1];
+    out[2] = 0f32;
+    out[3] = 0f32;
+    out[4] = m[2];
+    out[5] = m[3];
+    out[6] = 0f32;
+    out[7] = 0f32;
+    out[8] = 0f32;
+    out[9] = 0f32;
+    out[10] = 1f32;
+    out[11] = 0f32;
+    out[12] = m[4];
+    out[13] = m[5];
+    out[14] = 0f32;
+    out[15] = 1f32;
+}
+
+pub fn transform_mat4_point(m: &[f32; 16], v: &[f32; 3]) -> [f32; 3] {
+    let x = v[0];
+    let y = v[1];
+    let z = v[2];
+    let w = m[3] * x + m[7] * y + m[11] * z + m[15];
+    let iw = if w != 0f32 {1f32 / w} else {1f32};
+
+    [
+        (m[0] * x + m[4] * y + m[8] * z + m[12]) * iw,
+        (m[1] * x + m[5] * y + m[9] * z + m[13]) * iw,
+        (m[2] * x + m[6] * y + m[10] * z + m[14]) * iw
+    ]
+}
+
+pub fn decompose_mat32(m: &[f32; 6]) -> ([f32; 2], [f32; 2], [f32; 2]) {
+    let sx = length2(m[0], m[1]);
+    let sy = length2(m[2], m[3]);
+    let rotation = if sx > 0f32 {[m[0] / sx, m[1] / sx]} else {[1f32, 0f32]};
+
+    ([m[4], m[5]], rotation, [sx, sy])
+}
+pub fn compose_mat32(out: &mut [f32; 6], position: &[f32; 2], rotation: &[f32; 2], scale: &[f32; 2]) {
+    let c = rotation[0];
+    let s = rotation[1];
+
+    out[0] = c * scale[0];
+    out[1] = s * scale[0];
+    out[2] = -s * scale[1];
+    out[3] = c * scale[1];
+    out[4] = position[0];
+    out[5] = position[1];
+}
+pub fn nlerp_rotation2(a: &[f32; 2], b: &[f32; 2], t: f32) -> [f32; 2] {
+    let x = lerp(a[0], b[0], t);
+    let y = lerp(a[1], b[1], t);
+    let l = length2(x, y);
+
+    if l > 0f32 {[x / l, y / l]} else {*b}
+}
+
+pub fn decompose_mat4(m: &[f32; 16]) -> ([f32; 3], [f32; 4], [f32; 3]) {
+    let sx = length3(m[0], m[1], m[2]);
+    let sy = length3(m[4], m[5], m[6]);
+    let sz = length3(m[8], m[9], m[10]);
+
+    let isx = if sx > 0f32 {1f32 / sx} else {0f32};
+    let isy = if sy > 0f32 {1f32 / sy} else {0f32};
+    let isz = if sz > 0f32 {1f32 / sz} else {0f32};
+
+    let r = [
+        m[0] * isx, m[1] * isx, m[2] * isx,
+        m[4] * isy, m[5] * isy, m[6] * isy,
+        m[8] * isz, m[9] * isz, m[10] * isz
+    ];
+
+    ([m[12], m[13], m[14]], quat_from_mat3(&r), [sx, sy, sz])
+}
+pub fn compose_mat4(out: &mut [f32; 16], position: &[f32; 3], rotation: &[f32; 4], scale: &[f32; 3]) {
+    let x = rotation[0];
+    let y = rotation[1];
+    let z = rotation[2];
+    let w = rotation[3];
+
+    let x2 = x + x;
+    let y2 = y + y;
+    let z2 = z + z;
+    let xx = x * x2;
+    let xy = x * y2;
+    let xz = x * z2;
+    let yy = y * y2;
+    let yz = y * z2;
+    let zz = z * z2;
+    let wx = w * x2;
+    let wy = w * y2;
+    let wz = w * z2;
+
+    out[0] = (1f32 - (yy + zz)) * scale[0];
+    out[1] = (xy + wz) * scale[0];
+    out[2] = (xz - wy) * scale[0];
+    out[3] = 0f32;
+    out[4] = (xy - wz) * scale[1];
+    out[5] = (1f32 - (xx + zz)) * scale[1];
+    out[6] = (yz + wx) * scale[1];
+    out[7] = 0f32;
+    out[8] = (xz + wy) * scale[2];
+    out[9] = (yz - wx) * scale[2];
+    out[10] = (1f32 - (xx + yy)) * scale[2];
+    out[11] = 0f32;
+    out[12] = position[0];
+    out[13] = position[1];
+    out[14] = position[2];
+    out[15] = 1f32;
+}
+
+pub fn quat_from_mat3(m: &[f32; 9]) -> [f32; 4] {
+    let trace = m[0] + m[4] + m[8];
+
+    if trace > 0f32 {
+        let s = 0.5f32 / sqrt(trace + 1f32);
+        [(m[5] - m[7]) * s, (m[6] - m[2]) * s, (m[1] - m[3]) * s, 0.25f32 / s]
+    } else if m[0] > m[4] && m[0] > m[8] {
+        let s = 2f32 * sqrt(1f32 + m[0] - m[4] - m[8]);
+        [0.25f32 * s, (m[3] + m[1]) / s, (m[6] + m[2]) / s, (m[5] - m[7]) / s]
+    } else if m[4] > m[8] {
+        let s = 2f32 * sqrt(1f32 + m[4] - m[0] - m[8]);
+        [(m[3] + m[1]) / s, 0.25f32 * s, (m[7] + m[5]) / s, (m[6] - m[2]) / s]
+    } else {
+        let s = 2f32 * sqrt(1f32 + m[8] - m[0] - m[4]);
+        [(m[6] + m[2]) / s, (m[7] + m[5]) / s, 0.25f32 * s, (m[1] - m[3]) / s]
+    }
+}
+pub fn quat_nlerp(a: &[f32; 4], b: &[f32; 4], t: f32) -> [f32; 4] {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    let sign = if dot < 0f32 {-1f32} else {1f32};
+
+    let x = lerp(a[0], b[0] * sign, t);
+    let y = lerp(a[1], b[1] * sign, t);
+    let z = lerp(a[2], b[2] * sign, t);
+    let w = lerp(a[3], b[3] * sign, t);
+    let l = sqrt(x * x + y * y + z * z + w * w);
+
+    if l > 0f32 {[x / l, y / l, z / l, w / l]} else {*b}
+}
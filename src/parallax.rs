@@ -0,0 +1,24 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxLayer {
+    pub factor: [f32; 2],
+    pub zoom_factor: f32,
+}
+
+impl ParallaxLayer {
+    pub fn new(factor: f32) -> Self {
+        ParallaxLayer {
+            factor: [factor, factor],
+            zoom_factor: 1f32,
+        }
+    }
+    pub fn new_xy(factor: &[f32; 2]) -> Self {
+        ParallaxLayer {
+            factor: *factor,
+            zoom_factor: 1f32,
+        }
+    }
+    pub fn with_zoom_factor(mut self, zoom_factor: f32) -> Self {
+        self.zoom_factor = zoom_factor;
+        self
+    }
+}
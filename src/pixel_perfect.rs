@@ -0,0 +1,71 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use camera_error::CameraError;
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PixelPerfect {
+    reference_width: usize,
+    reference_height: usize,
+    pixels_per_unit: f32,
+}
+
+impl PixelPerfect {
+    pub fn new(reference_width: usize, reference_height: usize, pixels_per_unit: f32) -> Self {
+        PixelPerfect {
+            reference_width: if reference_width > 0usize {reference_width} else {1usize},
+            reference_height: if reference_height > 0usize {reference_height} else {1usize},
+            pixels_per_unit: if pixels_per_unit.is_finite() && pixels_per_unit > 0f32 {pixels_per_unit} else {1f32},
+        }
+    }
+    pub fn try_new(reference_width: usize, reference_height: usize, pixels_per_unit: f32) -> Result<Self, CameraError> {
+        if reference_width == 0usize {
+            Err(CameraError::InvalidWidth(reference_width))
+        } else if reference_height == 0usize {
+            Err(CameraError::InvalidHeight(reference_height))
+        } else if !(pixels_per_unit.is_finite() && pixels_per_unit > 0f32) {
+            Err(CameraError::InvalidPixelsPerUnit(pixels_per_unit))
+        } else {
+            Ok(PixelPerfect::new(reference_width, reference_height, pixels_per_unit))
+        }
+    }
+
+    pub fn get_reference_width(&self) -> usize {
+        self.reference_width
+    }
+    pub fn get_reference_height(&self) -> usize {
+        self.reference_height
+    }
+    pub fn get_pixels_per_unit(&self) -> f32 {
+        self.pixels_per_unit
+    }
+
+    pub fn get_scale(&self, width: usize, height: usize) -> usize {
+        let sx = width / self.reference_width;
+        let sy = height / self.reference_height;
+        let scale = if sx < sy {sx} else {sy};
+
+        if scale > 0usize {scale} else {1usize}
+    }
+    pub fn get_viewport(&self, width: usize, height: usize) -> [usize; 4] {
+        let scale = self.get_scale(width, height);
+        let w = self.reference_width * scale;
+        let h = self.reference_height * scale;
+        let x = if width > w {(width - w) / 2usize} else {0usize};
+        let y = if height > h {(height - h) / 2usize} else {0usize};
+
+        [x, y, w, h]
+    }
+    pub fn get_orthographic_size(&self) -> f32 {
+        self.reference_height as f32 / (2f32 * self.pixels_per_unit)
+    }
+    pub fn get_aspect(&self) -> f32 {
+        self.reference_width as f32 / self.reference_height as f32
+    }
+    pub fn snap(&self, value: f32) -> f32 {
+        math::round(value * self.pixels_per_unit) / self.pixels_per_unit
+    }
+}
@@ -0,0 +1,123 @@
+use camera3d_desc::Camera3DDesc;
+use camera2d_desc::Camera2DDesc;
+use pixel_perfect::PixelPerfect;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Camera3DPreset {
+    Wide18mm,
+    Wide24mm,
+    Film35mm,
+    Normal50mm,
+    Portrait85mm,
+    Telephoto200mm,
+    Isometric,
+}
+
+impl Camera3DPreset {
+    pub fn all() -> &'static [Camera3DPreset] {
+        static PRESETS: [Camera3DPreset; 7] = [
+            Camera3DPreset::Wide18mm,
+            Camera3DPreset::Wide24mm,
+            Camera3DPreset::Film35mm,
+            Camera3DPreset::Normal50mm,
+            Camera3DPreset::Portrait85mm,
+            Camera3DPreset::Telephoto200mm,
+            Camera3DPreset::Isometric,
+        ];
+        &PRESETS
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match *self {
+            Camera3DPreset::Wide18mm => "wide 18mm",
+            Camera3DPreset::Wide24mm => "wide 24mm",
+            Camera3DPreset::Film35mm => "35mm film",
+            Camera3DPreset::Normal50mm => "normal 50mm",
+            Camera3DPreset::Portrait85mm => "portrait 85mm",
+            Camera3DPreset::Telephoto200mm => "telephoto 200mm",
+            Camera3DPreset::Isometric => "isometric",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Camera3DPreset> {
+        Camera3DPreset::all().iter().find(|preset| preset.get_name() == name).map(|preset| *preset)
+    }
+
+    pub fn apply(&self, desc: Camera3DDesc) -> Camera3DDesc {
+        match *self {
+            Camera3DPreset::Wide18mm => perspective(desc, 67.3801f32),
+            Camera3DPreset::Wide24mm => perspective(desc, 53.1301f32),
+            Camera3DPreset::Film35mm => perspective(desc, 37.8493f32),
+            Camera3DPreset::Normal50mm => perspective(desc, 26.9915f32),
+            Camera3DPreset::Portrait85mm => perspective(desc, 16.0714f32),
+            Camera3DPreset::Telephoto200mm => perspective(desc, 6.8673f32)
+                .near(1f32)
+                .far(10000f32),
+            Camera3DPreset::Isometric => desc
+                .orthographic_mode(true)
+                .orthographic_size(10f32)
+                .near(0.1f32)
+                .far(1000f32),
+        }
+    }
+    pub fn desc(&self) -> Camera3DDesc {
+        self.apply(Camera3DDesc::new())
+    }
+}
+
+fn perspective(desc: Camera3DDesc, fov: f32) -> Camera3DDesc {
+    desc
+        .orthographic_mode(false)
+        .fov(fov)
+        .near(0.1f32)
+        .far(1000f32)
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Camera2DPreset {
+    TopDown,
+    PixelPerfect16,
+    PixelPerfect32,
+}
+
+impl Camera2DPreset {
+    pub fn all() -> &'static [Camera2DPreset] {
+        static PRESETS: [Camera2DPreset; 3] = [
+            Camera2DPreset::TopDown,
+            Camera2DPreset::PixelPerfect16,
+            Camera2DPreset::PixelPerfect32,
+        ];
+        &PRESETS
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match *self {
+            Camera2DPreset::TopDown => "top-down",
+            Camera2DPreset::PixelPerfect16 => "top-down pixel-perfect 16px/unit",
+            Camera2DPreset::PixelPerfect32 => "top-down pixel-perfect 32px/unit",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Camera2DPreset> {
+        Camera2DPreset::all().iter().find(|preset| preset.get_name() == name).map(|preset| *preset)
+    }
+
+    pub fn apply(&self, desc: Camera2DDesc) -> Camera2DDesc {
+        match *self {
+            Camera2DPreset::TopDown => desc.orthographic_size(10f32),
+            Camera2DPreset::PixelPerfect16 => pixel_perfect(desc, 16f32),
+            Camera2DPreset::PixelPerfect32 => pixel_perfect(desc, 32f32),
+        }
+    }
+    pub fn desc(&self) -> Camera2DDesc {
+        self.apply(Camera2DDesc::new())
+    }
+}
+
+fn pixel_perfect(desc: Camera2DDesc, pixels_per_unit: f32) -> Camera2DDesc {
+    let pixel_perfect = PixelPerfect::new(desc.width, desc.height, pixels_per_unit);
+    desc
+        .orthographic_size(pixel_perfect.get_orthographic_size())
+        .pixel_perfect(Some(pixel_perfect))
+}
+
@@ -0,0 +1,97 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RenderTargetKind {
+    Screen,
+    Texture(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PixelFormat {
+    RGBA8,
+    SRGBA8,
+    RGB10A2,
+    R11G11B10F,
+    RGBA16F,
+    RGBA32F,
+    Depth16,
+    Depth24Stencil8,
+    Depth32F,
+}
+
+impl PixelFormat {
+    pub fn is_depth(&self) -> bool {
+        match *self {
+            PixelFormat::Depth16 | PixelFormat::Depth24Stencil8 | PixelFormat::Depth32F => true,
+            _ => false,
+        }
+    }
+    pub fn is_float(&self) -> bool {
+        match *self {
+            PixelFormat::R11G11B10F | PixelFormat::RGBA16F | PixelFormat::RGBA32F | PixelFormat::Depth32F => true,
+            _ => false,
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RenderTarget {
+    pub kind: RenderTargetKind,
+    pub width: usize,
+    pub height: usize,
+    pub format: PixelFormat,
+    pub samples: usize,
+    pub hdr: bool,
+}
+
+impl RenderTarget {
+    pub fn screen(width: usize, height: usize) -> Self {
+        RenderTarget {
+            kind: RenderTargetKind::Screen,
+            width: width,
+            height: height,
+            format: PixelFormat::RGBA8,
+            samples: 1usize,
+            hdr: false,
+        }
+    }
+    pub fn texture(id: usize, width: usize, height: usize) -> Self {
+        RenderTarget {
+            kind: RenderTargetKind::Texture(id),
+            width: width,
+            height: height,
+            format: PixelFormat::RGBA8,
+            samples: 1usize,
+            hdr: false,
+        }
+    }
+
+    pub fn with_format(mut self, format: PixelFormat) -> Self {
+        self.format = format;
+        self
+    }
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = if samples > 0usize {samples} else {1usize};
+        self
+    }
+    pub fn with_hdr(mut self, hdr: bool) -> Self {
+        self.hdr = hdr;
+        self
+    }
+
+    pub fn is_screen(&self) -> bool {
+        self.kind == RenderTargetKind::Screen
+    }
+    pub fn get_texture(&self) -> Option<usize> {
+        match self.kind {
+            RenderTargetKind::Texture(id) => Some(id),
+            RenderTargetKind::Screen => None,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+
+use mat4;
+
+use camera3d::Camera3D;
+use math;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadowCascade {
+    pub near: f32,
+    pub far: f32,
+    pub corners: [[f32; 3]; 8],
+    pub center: [f32; 3],
+    pub radius: f32,
+    pub view: [f32; 16],
+    pub projection: [f32; 16],
+    pub view_projection: [f32; 16],
+}
+
+pub fn compute_cascade_splits(near: f32, far: f32, count: usize, lambda: f32) -> Vec<f32> {
+    let count = if count > 0usize {count} else {1usize};
+    let mut splits = Vec::with_capacity(count + 1usize);
+
+    for i in 0..(count + 1usize) {
+        let p = i as f32 / count as f32;
+        let log = near * math::pow(far / near, p);
+        let uniform = near + (far - near) * p;
+
+        splits.push(math::lerp(uniform, log, lambda));
+    }
+    splits
+}
+
+pub fn compute_shadow_cascades(
+    camera: &mut Camera3D, light_direction: &[f32; 3], count: usize, lambda: f32, shadow_map_size: usize,
+    z_extension: f32
+) -> Vec<ShadowCascade> {
+    let splits = compute_cascade_splits(camera.get_near(), camera.get_far(), count, lambda);
+    let z_extension = if z_extension > 0f32 {z_extension} else {0f32};
+    let direction = math::normalize3(light_direction);
+    let up = if math::abs(direction[1]) > 0.99f32 {[0f32, 0f32, 1f32]} else {[0f32, 1f32, 0f32]};
+    let size = shadow_map_size as f32;
+
+    let mut cascades = Vec::with_capacity(splits.len() - 1usize);
+
+    for i in 0..(splits.len() - 1usize) {
+        let near = splits[i];
+        let far = splits[i + 1usize];
+        let slice = camera.get_frustum_slice(near, far);
+        let center = slice.center;
+        let radius = math::ceil(slice.radius * 16f32) / 16f32;
+
+        let eye = [
+            center[0] - direction[0] * radius,
+            center[1] - direction[1] * radius,
+            center[2] - direction[2] * radius
+        ];
+
+        let mut view = mat4::new_identity();
+        let mut projection = mat4::new_identity();
+        let mut view_projection = mat4::new_identity();
+
+        math::look_at(&mut view, &eye, &center, &up);
+        mat4::orthographic(&mut projection, -radius, radius, radius, -radius, -z_extension, radius * 2f32);
+        mat4::mul(&mut view_projection, &projection, &view);
+
+        let origin = math::transform_mat4_point(&view_projection, &[0f32, 0f32, 0f32]);
+        let half_size = size * 0.5f32;
+        let x = origin[0] * half_size;
+        let y = origin[1] * half_size;
+
+        projection[12] += (math::round(x) - x) / half_size;
+        projection[13] += (math::round(y) - y) / half_size;
+        mat4::mul(&mut view_projection, &projection, &view);
+
+        cascades.push(ShadowCascade {
+            near: near,
+            far: far,
+            corners: slice.corners,
+            center: center,
+            radius: radius,
+            view: view,
+            projection: projection,
+            view_projection: view_projection,
+        });
+    }
+
+    cascades
+}
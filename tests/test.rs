@@ -5,9 +5,18 @@ extern crate scene_graph;
 extern crate transform_components;
 
 
-use camera_components::{Camera3D, Camera3DManager};
+use camera_components::{compute_cascade_splits, AspectPolicy, Axonometric, CameraError, CameraUniforms, ClusterGrid, Easing, RenderTarget, PixelPerfect, Layer, ParallaxLayer, Camera2D, Camera3D, Camera3DDesc, Camera2DDesc, Camera3DManager, Camera3DPreset, Camera2DPreset, Camera3DPath, CameraKeyframe, PathInterpolation};
 use scene_graph::{Scene, Entity};
-use transform_components::Transform2D;
+use transform_components::{Transform2D, Transform3D};
+
+
+fn assert_near(a: f32, b: f32) {
+    assert_near_eps(a, b, 0.0001f32);
+}
+fn assert_near_eps(a: f32, b: f32, eps: f32) {
+    let d = a - b;
+    assert!(d < eps && d > -eps, "{} != {}", a, b);
+}
 
 
 #[test]
@@ -42,3 +51,699 @@ fn test_scene() {
         0f32, 0f32, -0.002000002f32, 0f32
     ]);
 }
+
+
+#[test]
+fn test_camera3d_path_sample() {
+    let mut path = Camera3DPath::new();
+
+    path
+        .set_interpolation(PathInterpolation::Linear)
+        .add_keyframe(CameraKeyframe::new(2f32, &[10f32, 0f32, 0f32], &[0f32, 0f32, -1f32], 60f32, 0f32))
+        .add_keyframe(CameraKeyframe::new(0f32, &[0f32, 0f32, 0f32], &[0f32, 0f32, -1f32], 40f32, 0f32));
+
+    assert_eq!(path.get_duration(), 2f32);
+
+    let keyframe = path.sample(1f32).unwrap();
+    assert_eq!(keyframe.position, [5f32, 0f32, 0f32]);
+    assert_eq!(keyframe.fov, 50f32);
+}
+
+
+#[test]
+fn test_camera3d_desc() {
+    let desc = Camera3DDesc::new()
+        .size(1280usize, 720usize)
+        .fov(60f32)
+        .near(0.1f32)
+        .far(500f32);
+
+    let camera3d = desc.build();
+
+    assert_eq!(camera3d.get_width(), 1280usize);
+    assert_eq!(camera3d.get_fov(), 60f32);
+    assert_eq!(camera3d.describe(), desc);
+
+    let desc = desc.near(0.00005f32).orthographic_size(0.00005f32);
+    assert_eq!(desc.validate(), Ok(()));
+    assert_eq!(desc.build().describe(), desc);
+}
+
+
+#[test]
+fn test_camera3d_try_set() {
+    let mut camera3d = Camera3D::new();
+
+    assert_eq!(camera3d.try_set_far(0.0001f32).err(), Some(CameraError::InvalidFar(0.001f32, 0.0001f32)));
+    assert_eq!(camera3d.try_set_fov(180f32).err(), Some(CameraError::InvalidFov(180f32)));
+    assert_eq!(camera3d.try_set_width(0usize).err(), Some(CameraError::InvalidWidth(0usize)));
+    assert!(camera3d.try_set_near(0.1f32).is_ok());
+    assert_eq!(camera3d.get_near(), 0.1f32);
+}
+
+
+#[test]
+fn test_camera_uniforms_layout() {
+    let mut camera3d = Camera3D::new();
+    let uniforms = CameraUniforms::from_camera3d(&mut camera3d, 0f32);
+
+    assert_eq!(uniforms.as_bytes().len(), 432usize);
+    assert_eq!(uniforms.inv_size, [1f32 / 512f32, 1f32 / 512f32]);
+}
+
+
+#[test]
+fn test_compute_cascade_splits() {
+    let splits = compute_cascade_splits(1f32, 100f32, 2usize, 0f32);
+    assert_eq!(&splits[..], &[1f32, 50.5f32, 100f32]);
+}
+
+
+#[test]
+fn test_pixel_perfect_viewport() {
+    let pixel_perfect = PixelPerfect::new(320usize, 180usize, 16f32);
+
+    assert_eq!(pixel_perfect.get_scale(1000usize, 600usize), 3usize);
+    assert_eq!(pixel_perfect.get_viewport(1000usize, 600usize), [20usize, 30usize, 960usize, 540usize]);
+    assert_eq!(pixel_perfect.get_orthographic_size(), 5.625f32);
+
+    let clamped = PixelPerfect::new(0usize, 0usize, 0f32);
+    assert_eq!(clamped.get_scale(1000usize, 600usize), 600usize);
+    assert_eq!(clamped.snap(1.25f32), 1f32);
+
+    assert_eq!(PixelPerfect::try_new(320usize, 0usize, 16f32).err(), Some(CameraError::InvalidHeight(0usize)));
+    assert_eq!(PixelPerfect::try_new(320usize, 180usize, 0f32).err(), Some(CameraError::InvalidPixelsPerUnit(0f32)));
+}
+
+
+#[test]
+fn test_aspect_policy_fit() {
+    let letterbox = AspectPolicy::Letterbox.fit(2f32, 800usize, 800usize);
+    assert_eq!(letterbox.viewport, [0usize, 200usize, 800usize, 400usize]);
+    assert_eq!(letterbox.scale, [1f32, 1f32]);
+
+    let expand = AspectPolicy::Expand.fit(1f32, 800usize, 400usize);
+    assert_eq!(expand.viewport, [0usize, 0usize, 800usize, 400usize]);
+    assert_eq!(expand.scale, [2f32, 1f32]);
+}
+
+
+#[test]
+fn test_camera2d_zoom_to_point() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera2d = Camera2D::new();
+    let mut transform = Transform2D::new();
+
+    transform.set_position(&[4f32, -2f32]);
+
+    entity
+        .add_component(camera2d.clone())
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    camera2d
+        .set_offset(&[1f32, 3f32])
+        .set_rotation(0.5f32)
+        .set_local_zoom(2f32);
+
+    let point = [128f32, 384f32];
+    let anchor = camera2d.screen_to_world(&point);
+
+    camera2d.zoom_to_point(8f32, &point, 1f32, Easing::QuadInOut);
+
+    camera2d.update_zoom(0.5f32);
+    let world = camera2d.screen_to_world(&point);
+    assert_near(world[0], anchor[0]);
+    assert_near(world[1], anchor[1]);
+
+    camera2d.update_zoom(0.5f32);
+    let world = camera2d.screen_to_world(&point);
+    assert_near(world[0], anchor[0]);
+    assert_near(world[1], anchor[1]);
+
+    assert!(!camera2d.is_zooming());
+    assert_eq!(camera2d.get_orthographic_size(), 8f32);
+}
+
+
+#[test]
+fn test_camera2d_view3d_projection3d() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera2d = Camera2D::new();
+    let mut transform = Transform2D::new();
+
+    transform.set_position(&[4f32, -2f32]);
+
+    entity
+        .add_component(camera2d.clone())
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    camera2d
+        .set(512usize, 256usize)
+        .set_orthographic_size(4f32)
+        .set_near(1f32)
+        .set_far(9f32);
+
+    let view = *camera2d.get_view();
+    let view3d = *camera2d.get_view3d();
+    assert_eq!(view3d, [
+        view[0], view[1], 0f32, 0f32,
+        view[2], view[3], 0f32, 0f32,
+        0f32, 0f32, 1f32, 0f32,
+        view[4], view[5], 0f32, 1f32
+    ]);
+    assert_near(view3d[12], -4f32);
+    assert_near(view3d[13], 2f32);
+
+    let projection = *camera2d.get_projection();
+    let projection3d = *camera2d.get_projection3d();
+    assert_near(projection3d[0], projection[0]);
+    assert_near(projection3d[5], projection[3]);
+    assert_near(projection3d[0], 1f32 / 8f32);
+    assert_near(projection3d[5], 1f32 / 4f32);
+
+    let depth = |z: f32| projection3d[10] * z + projection3d[14];
+    assert_near(depth(-1f32), -1f32);
+    assert_near(depth(-9f32), 1f32);
+    assert_near(depth(-5f32), 0f32);
+}
+
+#[test]
+fn test_camera2d_parallax() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera2d = Camera2D::new();
+    let mut transform = Transform2D::new();
+
+    transform.set_position(&[10f32, 6f32]);
+
+    entity
+        .add_component(camera2d.clone())
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    camera2d
+        .set(512usize, 512usize)
+        .set_orthographic_size(4f32)
+        .set_local_zoom(2f32);
+
+    let view = *camera2d.get_view();
+    let projection = *camera2d.get_projection();
+
+    let fixed = ParallaxLayer::new(0f32).with_zoom_factor(0f32);
+    let parallax_view = camera2d.get_parallax_view(&fixed);
+    for i in 0..6 {
+        assert_near(parallax_view[i], [1f32, 0f32, 0f32, 1f32, 0f32, 0f32][i]);
+    }
+    assert_near(camera2d.get_parallax_projection(&fixed, 2f32)[0], 0.5f32);
+
+    let full = ParallaxLayer::new(1f32);
+    let parallax_view = camera2d.get_parallax_view(&full);
+    let parallax_projection = camera2d.get_parallax_projection(&full, 2f32);
+    for i in 0..6 {
+        assert_near(parallax_view[i], view[i]);
+        assert_near(parallax_projection[i], projection[i]);
+    }
+
+    let half = ParallaxLayer::new(0.5f32).with_zoom_factor(0.5f32);
+    let parallax_view = camera2d.get_parallax_view(&half);
+    let s = 1f32 / 0.75f32;
+    for i in 0..6 {
+        assert_near(parallax_view[i], [s, 0f32, 0f32, s, -5f32 * s, -3f32 * s][i]);
+    }
+    let parallax_projection = camera2d.get_parallax_projection(&half, 2f32);
+    assert_near(parallax_projection[0], 1f32 / 3f32);
+    assert_near(parallax_projection[3], 1f32 / 3f32);
+}
+
+#[test]
+fn test_camera3d_morph() {
+    let mut camera3d = Camera3D::new();
+
+    camera3d.morph_to_orthographic(10f32, 1f32, Easing::Linear);
+    camera3d.update_morph(0.5f32);
+
+    assert!(camera3d.is_morphing());
+    let uniforms = CameraUniforms::from_camera3d(&mut camera3d, 0f32);
+    assert!(uniforms.position[2] > 0f32);
+
+    camera3d.update_morph(0.5f32);
+
+    assert!(!camera3d.is_morphing());
+    assert!(camera3d.get_orthographic_mode());
+    assert_near(camera3d.get_orthographic_size(), 3.1529878f32);
+    assert_eq!(camera3d.get_view()[14], 0f32);
+
+    camera3d.morph_to_perspective(1f32, Easing::Linear);
+    camera3d.update_morph(1f32);
+
+    assert!(!camera3d.is_morphing());
+    assert!(!camera3d.get_orthographic_mode());
+    assert_eq!(camera3d.get_fov(), 35f32);
+}
+
+
+#[test]
+fn test_camera3d_look_at() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+    let mut transform = Transform3D::new();
+
+    transform.set_position(&[0f32, 10f32, 0f32]);
+
+    entity
+        .add_component(camera3d.clone())
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    camera3d.look_at(&[0f32, 0f32, 0f32], &[0f32, 1f32, 0f32]);
+
+    let view = *camera3d.get_view();
+    for value in view.iter() {
+        assert!(!value.is_nan());
+    }
+    assert_near(view[12], 0f32);
+    assert_near(view[13], 0f32);
+    assert_near(view[14], -10f32);
+
+    camera3d.look_at(&[0f32, 10f32, 0f32], &[0f32, 1f32, 0f32]);
+    assert_eq!(camera3d.get_view(), &view);
+}
+
+
+#[test]
+fn test_camera3d_render_target() {
+    let mut camera3d = Camera3D::new();
+
+    camera3d.set_render_target(RenderTarget::texture(1usize, 256usize, 128usize));
+    assert_eq!(camera3d.get_width(), 256usize);
+    assert_eq!(camera3d.get_aspect(), 2f32);
+
+    camera3d.resize_screen(1920usize, 1080usize);
+    camera3d.set_width(64usize);
+    assert_eq!(camera3d.get_width(), 256usize);
+    assert_eq!(camera3d.get_render_target().width, 256usize);
+    assert_eq!(camera3d.get_render_target().height, 128usize);
+    assert_eq!(camera3d.try_set_width(64usize).err(), Some(CameraError::InvalidRenderTargetSize(64usize, 128usize)));
+    assert_eq!(camera3d.describe().validate(), Ok(()));
+
+    let mut desc = Camera3DDesc::new().render_target(RenderTarget::texture(1usize, 256usize, 128usize));
+    assert!(desc.validate().is_ok());
+
+    desc.width = 640usize;
+    assert_eq!(desc.validate().err(), Some(CameraError::InvalidRenderTargetSize(640usize, 128usize)));
+    assert_eq!(desc.build().get_width(), 256usize);
+}
+
+
+#[test]
+fn test_layer_mask() {
+    let mut layer = Layer::new();
+
+    layer.add_layer(3usize).add_layer(40usize);
+    assert!(layer.has_layer(0usize));
+    assert!(layer.has_layer(3usize));
+    assert!(!layer.has_layer(40usize));
+    assert_eq!(layer.get_mask(), 0b1001u32);
+
+    layer.remove_layer(0usize).remove_layer(64usize);
+    assert_eq!(layer.get_mask(), 0b1000u32);
+}
+
+
+#[test]
+fn test_camera_culling() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+    let mut transform = Transform3D::new();
+
+    transform.set_position(&[0f32, 0f32, 5f32]);
+
+    entity
+        .add_component(Layer::new_with_mask(0b10u32))
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    assert!(!camera3d.sees_entity(&entity));
+    assert!(!camera3d.sees_entity_with_radius(&entity, 1f32));
+    assert!(camera3d.sees_entity_with_radius(&entity, 6f32));
+
+    camera3d.set_layer_mask(0b01u32);
+    assert!(!camera3d.sees_entity_with_radius(&entity, 6f32));
+
+    let mut camera2d = Camera2D::new();
+    assert!(camera2d.sees_point(&[1.5f32, 0f32]));
+    assert!(!camera2d.sees_point(&[3f32, 0f32]));
+    assert!(camera2d.sees_circle(&[3f32, 0f32], 1.5f32));
+}
+
+
+#[test]
+fn test_camera_presets() {
+    for preset in Camera3DPreset::all() {
+        assert_eq!(Camera3DPreset::from_name(preset.get_name()), Some(*preset));
+        assert!(preset.desc().validate().is_ok());
+    }
+    for preset in Camera2DPreset::all() {
+        assert_eq!(Camera2DPreset::from_name(preset.get_name()), Some(*preset));
+        assert!(preset.desc().validate().is_ok());
+    }
+    assert_eq!(Camera3DPreset::from_name("35mm film"), Some(Camera3DPreset::Film35mm));
+    assert_eq!(Camera3DPreset::from_name("fisheye"), None);
+    let desc = Camera2DPreset::PixelPerfect16.desc();
+    assert_eq!(desc.orthographic_size, 16f32);
+    assert_eq!(desc.pixel_perfect, Some(PixelPerfect::new(512, 512, 16f32)));
+    assert_eq!(Camera2DPreset::PixelPerfect32.desc().pixel_perfect.map(|pixel_perfect| pixel_perfect.get_pixels_per_unit()), Some(32f32));
+
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+
+    entity
+        .add_component(camera3d.clone())
+        .add_component(Transform3D::new());
+
+    scene.add_entity(&mut entity);
+
+    camera3d.apply_preset(Camera3DPreset::Isometric);
+
+    assert!(camera3d.get_orthographic_mode());
+    assert_near(camera3d.get_view()[0], 0.70710677f32);
+}
+
+
+#[test]
+fn test_camera_try_set_orthographic_size() {
+    let mut camera3d = Camera3D::new();
+    assert!(camera3d.try_set_orthographic_size(0.00001f32).is_ok());
+    assert_eq!(camera3d.get_orthographic_size(), 0.00001f32);
+    assert_eq!(camera3d.try_set_orthographic_size(0f32).err(), Some(CameraError::InvalidOrthographicSize(0f32)));
+
+    let mut camera2d = Camera2D::new();
+    assert!(camera2d.try_set_near(-10f32).is_ok());
+    assert_eq!(camera2d.try_set_far(-20f32).err(), Some(CameraError::InvalidFar(-10f32, -20f32)));
+    assert_eq!(camera2d.try_set_near(1f32).err(), Some(CameraError::InvalidNear(1f32, 1f32)));
+    assert_eq!(camera2d.get_near(), -10f32);
+}
+
+
+#[test]
+fn test_camera3d_depth() {
+    let mut camera3d = Camera3D::new();
+    camera3d.set_near(0.1f32).set_far(100f32);
+
+    for &(orthographic_mode, reversed_z) in [(false, false), (true, false), (false, true), (true, true)].iter() {
+        camera3d
+            .set_orthographic_mode(orthographic_mode)
+            .set_reversed_z(reversed_z);
+
+        let depth = camera3d.depth_from_linear(2f32);
+        assert_near(camera3d.linearize_depth(depth), 2f32);
+
+        let position = camera3d.view_position_from_depth(&[256f32, 256f32], depth);
+        assert_near(position[0], 0f32);
+        assert_near(position[1], 0f32);
+        assert_near(position[2], -2f32);
+
+        let near_depth = camera3d.depth_from_linear(0.1f32);
+        assert_near(near_depth, if reversed_z {1f32} else {0f32});
+        assert_eq!(camera3d.get_clear_depth(), if reversed_z {0f32} else {1f32});
+    }
+
+    camera3d.set_reversed_z(true).set_reversed_z(true);
+    assert_eq!(camera3d.get_clear_depth(), 0f32);
+
+    let desc = Camera3DDesc::new().reversed_z(true);
+    assert_eq!(desc.clear_depth, 0f32);
+    assert_eq!(desc.build().describe(), desc);
+}
+
+
+#[test]
+fn test_camera3d_jitter() {
+    let mut camera3d = Camera3D::new();
+    camera3d
+        .set(512, 512)
+        .set_jitter_length(3)
+        .set_jitter_enabled(true);
+
+    let halton = [(0.5f32, 1f32 / 3f32), (0.25f32, 2f32 / 3f32), (0.75f32, 1f32 / 9f32)];
+
+    for &(x, y) in halton.iter().chain(halton.iter()) {
+        let projection = *camera3d.get_projection();
+        let unjittered = *camera3d.get_unjittered_projection();
+        let jitter = camera3d.get_jitter();
+
+        assert_near(jitter[0], x - 0.5f32);
+        assert_near(jitter[1], y - 0.5f32);
+
+        for i in 0..16 {
+            match i {
+                8 => assert_near(projection[i] - unjittered[i], -jitter[0] * 2f32 / 512f32),
+                9 => assert_near(projection[i] - unjittered[i], -jitter[1] * 2f32 / 512f32),
+                _ => assert_eq!(projection[i], unjittered[i]),
+            }
+        }
+
+        camera3d.advance_jitter();
+    }
+
+    camera3d.set_jitter_enabled(false);
+    assert_eq!(camera3d.get_jitter(), [0f32, 0f32]);
+    let unjittered = *camera3d.get_unjittered_projection();
+    assert_eq!(camera3d.get_projection(), &unjittered);
+}
+
+
+#[test]
+fn test_shadow_cascades_z_extension() {
+    let mut camera3d = Camera3D::new();
+    camera3d.set_near(0.1f32).set_far(100f32);
+
+    let cascades = camera3d.get_shadow_cascades(&[0f32, -1f32, 0f32], 2usize, 0.5f32, 1024usize, 50f32);
+    assert_eq!(cascades.len(), 2);
+
+    for cascade in cascades.iter() {
+        let m = cascade.view_projection;
+        let depth = |y: f32| {
+            m[2] * cascade.center[0] + m[6] * y + m[10] * cascade.center[2] + m[14]
+        };
+
+        let occluder = depth(cascade.center[1] + cascade.radius + 40f32);
+        assert!(occluder >= -1f32 && occluder <= 1f32);
+        assert!(depth(cascade.center[1] + cascade.radius + 60f32) < -1f32);
+    }
+}
+
+#[test]
+fn test_previous_view_projection() {
+    let mut camera3d = Camera3D::new();
+    let first = *camera3d.get_unjittered_projection();
+
+    camera3d.__end_frame();
+    assert_eq!(camera3d.get_previous_view_projection(), &first);
+
+    camera3d.set_fov(60f32);
+    let second = *camera3d.get_unjittered_projection();
+
+    camera3d.__end_frame();
+    assert_eq!(camera3d.get_previous_view_projection(), &first);
+
+    camera3d.__end_frame();
+    assert_eq!(camera3d.get_previous_view_projection(), &second);
+
+    let mut camera2d = Camera2D::new();
+    let first = *camera2d.get_projection();
+
+    camera2d.__end_frame();
+    camera2d.set_orthographic_size(4f32);
+    camera2d.__end_frame();
+    assert_eq!(camera2d.get_previous_view_projection(), &first);
+}
+
+
+#[test]
+fn test_cluster_grid() {
+    let mut camera3d = Camera3D::new();
+    camera3d.set_near(1f32).set_far(100f32);
+
+    let grid = ClusterGrid::new(&mut camera3d, 4usize, 3usize, 2usize);
+    let corners = camera3d.get_frustum_corners();
+
+    assert_eq!(grid.get_clusters().len(), 4usize * 3usize * 2usize);
+
+    let depths = grid.get_depths();
+    assert_eq!(depths.len(), 3usize);
+    assert_near(depths[0], 1f32);
+    assert_near_eps(depths[1], 10f32, 0.001f32);
+    assert_near_eps(depths[2], 100f32, 0.001f32);
+
+    let first = grid.get_cluster(0usize, 0usize, 0usize).unwrap();
+    assert_near(first.max[2], corners[0][2]);
+
+    let bottom_left = grid.get_cluster(0usize, 0usize, 1usize).unwrap();
+    let top_right = grid.get_cluster(3usize, 2usize, 1usize).unwrap();
+    for a in 0..2 {
+        assert_near_eps(bottom_left.min[a], corners[4][a], 0.01f32);
+        assert_near_eps(top_right.max[a], corners[6][a], 0.01f32);
+    }
+    assert_near_eps(bottom_left.min[2], corners[4][2], 0.001f32);
+    assert_near_eps(top_right.max[2], -10f32, 0.001f32);
+
+    assert_eq!(grid.get_slice(5f32), Some(0usize));
+    assert_eq!(grid.get_slice(50f32), Some(1usize));
+    assert_eq!(grid.get_slice(0.5f32), None);
+}
+
+
+#[test]
+fn test_camera_target_aspect() {
+    let mut camera3d = Camera3D::new();
+
+    camera3d
+        .set(400usize, 800usize)
+        .set_target_aspect(Some(1f32))
+        .set_aspect_policy(AspectPolicy::Expand);
+
+    assert_eq!(camera3d.get_projection_aspect(), (0.5f32, 2f32));
+    assert_near(camera3d.frame_orthographic_size_for_sphere(1f32), 1f32);
+
+    assert_eq!(camera3d.try_set_target_aspect(Some(0f32)).err(), Some(CameraError::InvalidTargetAspect(0f32)));
+    assert_eq!(camera3d.get_target_aspect(), Some(1f32));
+
+    camera3d.set_target_aspect(Some(-1f32));
+    assert_eq!(camera3d.get_target_aspect(), None);
+
+    let desc = Camera2DDesc::new().target_aspect(Some(-2f32));
+    assert_eq!(desc.validate().err(), Some(CameraError::InvalidTargetAspect(-2f32)));
+}
+
+
+#[test]
+fn test_camera3d_grid_round_trip() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+    let mut transform = Transform3D::new();
+
+    transform.set_position(&[10f32, 10f32, 10f32]);
+
+    entity
+        .add_component(camera3d.clone())
+        .add_component(transform);
+
+    scene.add_entity(&mut entity);
+
+    camera3d
+        .set_axonometric(Axonometric::Isometric)
+        .set_jitter_enabled(true);
+
+    let pixel = camera3d.grid_to_screen(&[3f32, -2f32], 0.5f32);
+    let grid = camera3d.screen_to_grid(&pixel, 0.5f32).unwrap();
+
+    assert_near_eps(grid[0], 3f32, 0.01f32);
+    assert_near_eps(grid[1], -2f32, 0.01f32);
+}
+
+
+#[test]
+fn test_camera3d_manager_blend() {
+    let mut scene = Scene::new();
+    let mut entity_a = Entity::new();
+    let mut entity_b = Entity::new();
+    let mut camera_a = Camera3D::new();
+    let mut camera_b = Camera3D::new();
+    let mut transform_b = Transform3D::new();
+
+    transform_b.set_position(&[10f32, 0f32, 0f32]);
+
+    entity_a
+        .add_component(camera_a.clone())
+        .add_component(Transform3D::new());
+    entity_b
+        .add_component(camera_b.clone())
+        .add_component(transform_b);
+
+    scene.add_entity(&mut entity_a);
+    scene.add_entity(&mut entity_b);
+
+    let mut camera3d_manager = scene.get_component_manager::<Camera3DManager>().unwrap();
+
+    camera3d_manager.set_active_camera(&mut camera_a);
+    camera3d_manager.set_active_camera_blended(&mut camera_b, 1f32, Easing::Linear);
+    assert!(camera3d_manager.is_transitioning());
+    assert_near(camera3d_manager.get_active_view().unwrap()[12], 0f32);
+
+    camera3d_manager.update_transition(0.5f32);
+    assert_near(camera3d_manager.get_active_view().unwrap()[12], -5f32);
+
+    camera3d_manager.update_transition(0.5f32);
+    assert!(!camera3d_manager.is_transitioning());
+    assert_near(camera3d_manager.get_active_view().unwrap()[12], -10f32);
+
+    camera3d_manager.set_active_camera_blended(&mut camera_a, 1f32, Easing::Linear);
+    camera3d_manager.update_transition(0.5f32);
+    camera_b.set_active();
+    assert!(!camera3d_manager.is_transitioning());
+    assert_near(camera3d_manager.get_active_view().unwrap()[12], -10f32);
+}
+
+
+#[test]
+fn test_camera3d_frame_sphere() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+
+    entity
+        .add_component(camera3d.clone())
+        .add_component(Transform3D::new());
+
+    scene.add_entity(&mut entity);
+
+    camera3d.frame_sphere(&[0f32, 0f32, -20f32], 1f32);
+
+    let distance = camera3d.frame_distance_for_sphere(1f32);
+    let world = camera3d.get_world_matrix().unwrap();
+    assert_near(world[12], 0f32);
+    assert_near_eps(world[14], distance - 20f32, 0.001f32);
+}
+
+
+#[test]
+fn test_camera3d_path_apply() {
+    let mut scene = Scene::new();
+    let mut entity = Entity::new();
+    let mut camera3d = Camera3D::new();
+    let mut path = Camera3DPath::new();
+
+    path
+        .set_interpolation(PathInterpolation::Linear)
+        .add_keyframe(CameraKeyframe::new(0f32, &[0f32, 0f32, 10f32], &[0f32, 0f32, 0f32], 40f32, 0f32))
+        .add_keyframe(CameraKeyframe::new(2f32, &[10f32, 0f32, 10f32], &[10f32, 0f32, 0f32], 60f32, 0f32));
+
+    entity
+        .add_component(camera3d.clone())
+        .add_component(path.clone())
+        .add_component(Transform3D::new());
+
+    scene.add_entity(&mut entity);
+
+    path.seek(1f32);
+
+    let world = camera3d.get_world_matrix().unwrap();
+    assert_near(world[12], 5f32);
+    assert_near(world[14], 10f32);
+    assert_near(world[10], 1f32);
+    assert_eq!(camera3d.get_fov(), 50f32);
+}